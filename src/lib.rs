@@ -1,6 +1,19 @@
 
 mod manager;
 
-pub use manager::manager::{Manager};
+pub use manager::manager::{Manager, DefinitionCheck, ChildCheck, DataMigration, Presence, Violation, MAX_DATA_DEPTH};
 pub use manager::data::data::Data;
-pub use manager::data::definition::definition::{Definition, Type};
+pub use manager::data::value::value::{Value, FromValue};
+pub use manager::data::temporal::temporal::{Date, Time, DateTime};
+pub use manager::data::blob::blob::Blob;
+pub use manager::data::reference::reference::{Reference, Step};
+pub use manager::data::decimal::decimal::Decimal;
+pub use manager::data::uuid::uuid::Uuid;
+pub use manager::data::vector::vector::{Distance, Neighbor, VectorIndex};
+pub use manager::data::geo::geo::{Point, BoundingBox, RTree};
+pub use manager::data::codec::codec::{Codec, ValueCodec};
+pub use manager::data::constraint::constraint::{Constraints, Number};
+pub use manager::data::definition::definition::{Definition, ChildDef, Type};
+pub use manager::error::error::{FractalError, Result};
+pub use manager::io_for_db::header::header::{FileHeader, FileKind};
+pub use manager::io_for_db::checksum::checksum::ChecksumAlgorithm;
@@ -3,8 +3,7 @@ pub mod manager;
 
 // use manager::manager::{Manager, static_manager};
 use manager::manager::{Manager};
-use manager::data::data::Data;
-use manager::data::definition::definition::{Definition, Type};
+use manager::data::definition::definition::Type;
 
 use std::path::PathBuf;
 
@@ -26,17 +25,17 @@ async fn main() {
         println!("{}", manager.get_def(&0xabcd_abcd).unwrap().explanation);
 
         static_manager().await.add_child(
-            Data::new(Definition::new(0xabcd_abcd, format!("children test"), Type::Int, false), Box::new(3)).unwrap(), 
+            Data::new(Definition::new(0xabcd_abcd, format!("children test"), Type::Int, false), 3).unwrap(), 
             &mut[]
         );
-        println!("{:?}", static_manager().await.get_data(&[(0xabcd_abcd, None)]).unwrap().get_value().unwrap().downcast_ref::<i32>().unwrap());
+        println!("{:?}", static_manager().await.get_data(&[(0xabcd_abcd, None)]).unwrap().get::<i32>().unwrap());
 
         static_manager().await.add_child(
-            Data::new(Definition::new(0x1234_5678, format!("string test"), Type::String, false), Box::new(format!("taro"))).unwrap(), 
+            Data::new(Definition::new(0x1234_5678, format!("string test"), Type::String, false), format!("taro")).unwrap(), 
             &mut[(0xabcd_abcd, None)]
         );
         println!("{:?}", static_manager().await.get_data(&[(0xabcd_abcd, None), (0x1234_5678, None)])
-            .unwrap().get_value().unwrap().downcast_ref::<String>().unwrap());
+            .unwrap().get::<String>().unwrap());
 
         static_manager().await.write_data().unwrap();
         static_manager().await.read_data().unwrap();
@@ -52,9 +51,10 @@ async fn main() {
     let mut manager: Manager = Manager::new(path, "data_test".to_string(), "data_name".to_string());
 
     // 2. Add definition.
-    manager.add_def(0xabcd_abcd, format!("abcd data"), Type::Int, false).unwrap();
-    manager.add_def(0x1234_5678, format!("1234 data"), Type::String, false).unwrap();
-    manager.get_def_mut(&0xabcd_abcd).unwrap().children.push(0x1234_5678);
+    manager.add_def(0xabcd_abcd, "abcd data".to_string(), Type::Int, false).unwrap();
+    manager.add_def(0x1234_5678, "1234 data".to_string(), Type::String, false).unwrap();
+    manager.set_child_range(&0xabcd_abcd, 0x1234_5678, 1, Some(1)).unwrap();
+    manager.get_def_mut(&0x1234_5678).unwrap().set_default("taro").unwrap();
 
     // 3. R/W definition on binary file.
     manager.write_def().unwrap();
@@ -64,20 +64,9 @@ async fn main() {
     println!("Def1[0xabcd_abcd]: {:?}", manager.get_def(&0xabcd_abcd).unwrap());
     println!("Def2[0x1234_5678]: {:?}", manager.get_def(&0x1234_5678).unwrap());
 
-    // 5. Add child data.
-    manager.add_child(
-        Data::new( 
-            (*manager.get_def(&0xabcd_abcd).unwrap()).clone(), 
-            Box::new(3)
-        ).unwrap(),
-        &mut[]
-    );
-    manager.add_child(
-        Data::new(
-            (*manager.get_def(&0x1234_5678).unwrap()).clone(), 
-            Box::new(format!("taro"))).unwrap(), 
-        &mut[(0xabcd_abcd, None)]
-    );
+    // 5. Add child data. The required child is made from its default value.
+    let data = manager.instantiate_with(&0xabcd_abcd, 3).unwrap();
+    manager.add_child(data, &[]).unwrap();
 
     // 6. R/W data on binary file.
     manager.write_data().unwrap();
@@ -85,7 +74,7 @@ async fn main() {
 
     // 7. Check data.
     println!("child1: {:?}", manager.get_data(&[(0xabcd_abcd, None)])
-            .unwrap().get_value().unwrap().downcast_ref::<i32>().unwrap());
+            .unwrap().get::<i32>().unwrap());
     println!("child2: {:?}", manager.get_data(&[(0xabcd_abcd, None), (0x1234_5678, None)])
-            .unwrap().get_value().unwrap().downcast_ref::<String>().unwrap());
+            .unwrap().get::<String>().unwrap());
 }
\ No newline at end of file
@@ -1,23 +1,119 @@
 
 pub mod data;
+pub mod error;
 pub mod io_for_db;
 
+#[allow(clippy::module_inception)]
 pub mod manager {
     
-    use crate::manager::data::definition::definition::{Definition, Type};
+    use crate::manager::data::definition::definition::{Definition, ChildDef, Type};
     use crate::manager::data::data::{Data, DataRoot};
+    use crate::manager::data::value::value::Value;
+    use crate::manager::data::blob::blob::{Blob, content_hash};
+    use crate::manager::data::reference::reference::Reference;
+    use crate::manager::data::vector::vector::{Distance, Neighbor, VectorIndex};
+    use crate::manager::data::geo::geo::{Point, BoundingBox, RTree};
+    use crate::manager::data::codec::codec::{Codec, ValueCodec};
+    use crate::manager::data::decimal::decimal::MAX_SCALE;
+    use crate::manager::data::constraint::constraint::{Constraints, Number};
     use crate::manager::io_for_db::path_manager::path_manager::PathManager;
-    use crate::manager::io_for_db::io::{BinaryManager, Mode, u128_to_slice};
-    use std::error;
-    use std::path::PathBuf;
+    use crate::manager::io_for_db::io::{BinaryManager, Mode};
+    use crate::manager::io_for_db::header::header::{FileHeader, FileKind, fingerprint};
+    use crate::manager::io_for_db::checksum::checksum::ChecksumAlgorithm;
+    use crate::manager::error::error::{FractalError, Result};
+    use std::collections::{HashMap, HashSet};
+    use std::fs;
+    use std::ops::{Bound, RangeBounds};
+    use std::path::Path;
+    use std::sync::{Arc, OnceLock};
 
-    type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
+    /// Max nesting of data. Deeper data is refused on add and read, so recursive functions do not overflow the stack.
+    pub const MAX_DATA_DEPTH: usize = 256;
+
+    /// Default size over which binary payloads are stored in blob files (64 KiB).
+    pub const DEFAULT_BLOB_THRESHOLD: usize = 64 * 1024;
+
+    /// Version of the canonical form of definitions which is hashed by `Manager::def_fingerprint`.  
+    /// It is changed only when the schema fields in the form are changed, not when the record format is changed.
+    pub const FINGERPRINT_VERSION: u8 = 1;
+
+    /// What to do when the data file is written against other definitions, or definitions read from file have issues.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum DefinitionCheck {
+        /// Return `FractalError::DefinitionMismatch` or the first issue of definitions.
+        Refuse,
+        /// Keep the warning and read the data. Warnings are taken by `Manager::take_warnings`.
+        Warn,
+    }
+
+    /// What to do when the added data is not allowed by definitions.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ChildCheck {
+        /// Return `FractalError::ChildNotAllowed` or `FractalError::DuplicateChild`.
+        Refuse,
+        /// Add the data. It is used for lenient imports.
+        Allow,
+    }
+
+    /// What to do with the data which uses the definition when it is removed, renamed or retagged.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum DataMigration {
+        /// Change nothing. The affected data are only listed.
+        DryRun,
+        /// Return `FractalError::DefinitionInUse` if any data uses the definition.
+        Reject,
+        /// Change the definition and the data.
+        Migrate,
+    }
+
+    /// Data which breaks the constraints of definitions. It is found by `Manager::validate`.
+    #[derive(Debug)]
+    pub struct Violation {
+        /// index path from the parent data.
+        pub index: Vec<usize>,
+        /// data root of the data. None if the data root cannot select it.
+        pub root: Option<Vec<(u32, Option<Data>)>>,
+        /// broken constraint.
+        pub error: FractalError,
+    }
+
+    /// State of child data which is used by `Manager::find_by_presence`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Presence {
+        /// The child has a value.
+        Value,
+        /// The child exists, but its value is null.
+        Null,
+        /// The child does not exist.
+        Missing,
+    }
 
     /// OurFractal DB Manager.
     pub struct Manager {
+        /// declared definitions.
         def_list: Vec<Definition>,
+        /// effective definitions of the tags which extend base definitions.
+        /// They are resolved when a definition is got, and cleared when definitions may be changed.
+        resolved_defs: OnceLock<HashMap<u32, Definition>>,
         parent_data: Data,
         path_manager: PathManager,
+        definition_check: DefinitionCheck,
+        /// issues which are found by read when definition check is warn.
+        warnings: Vec<FractalError>,
+        child_check: ChildCheck,
+        data_header: Option<FileHeader>,
+        checksum: ChecksumAlgorithm,
+        blob_threshold: usize,
+        /// distance of the tags which have vector index.
+        vector_index_settings: HashMap<u32, Distance>,
+        /// vector indexes. They are built again when they are searched after data is added.
+        vector_indexes: OnceLock<HashMap<u32, VectorIndex>>,
+        /// tags which have spatial index.
+        spatial_index_tags: HashSet<u32>,
+        /// spatial indexes. They are built again when they are searched after data is added.
+        spatial_indexes: OnceLock<HashMap<u32, RTree>>,
+        /// codecs of user defined types.
+        codecs: HashMap<u8, Arc<dyn ValueCodec>>,
     }
 
     impl Manager {
@@ -26,55 +122,428 @@ pub mod manager {
         /// * `path` - Data folder path.
         /// * `table_name` - Data table name.
         /// * `data_name` - Data name.
-        pub fn new(path: &PathBuf, table_name: String, data_name: String) -> Manager {
-            let def_list = vec![Definition::new(0x0000_0000, format!("Parent Tag"), Type::String, false)];
-            let children: Vec<u32> = Vec::new();
+        pub fn new(path: &Path, table_name: String, data_name: String) -> Manager {
+            let def_list = vec![Definition::new(0x0000_0000, "Parent Tag".to_string(), Type::String, false)];
+            let children: Vec<ChildDef> = Vec::new();
             let path_manager = PathManager::new(path.to_path_buf(), table_name, data_name.to_string());
             let data = Data::new(
                 Definition {
                     tag: 0x0000_0000,
-                    name: format!("Parent Tag"),
+                    name: "Parent Tag".to_string(),
                     data_type: Type::String,
-                    children: children,
+                    children,
                     explanation: String::new(),
                     is_base: true,
+                    base: None,
+                    is_recursive: false,
+                    max_depth: None,
                     is_multiple: true,
+                    is_nullable: false,
+                    members: Vec::new(),
+                    dimension: 0,
+                    scale: 0,
+                    constraints: Constraints::default(),
+                    default_value: None,
+                    codec: Codec::default(),
                 }, 
-                Box::new(data_name)
+                data_name
             ).unwrap();
             Manager{
                 parent_data: data,
-                def_list: def_list,
-                path_manager: path_manager,
+                def_list,
+                resolved_defs: OnceLock::new(),
+                path_manager,
+                definition_check: DefinitionCheck::Refuse,
+                warnings: Vec::new(),
+                child_check: ChildCheck::Refuse,
+                data_header: None,
+                checksum: ChecksumAlgorithm::default(),
+                blob_threshold: DEFAULT_BLOB_THRESHOLD,
+                vector_index_settings: HashMap::new(),
+                vector_indexes: OnceLock::new(),
+                spatial_index_tags: HashSet::new(),
+                spatial_indexes: OnceLock::new(),
+                codecs: HashMap::new(),
                 // is_connect: false,
             }
         }
 
+        /// set what to do when the data file is written against other definitions.
+        /// * `check` - Refuse or Warn.
+        pub fn set_definition_check(&mut self, check: DefinitionCheck) {
+            self.definition_check = check;
+        }
+
+        /// take the issues which are found by read when definition check is warn.
+        /// The warnings are cleared.
+        pub fn take_warnings(&mut self) -> Vec<FractalError> {
+            std::mem::take(&mut self.warnings)
+        }
+
+        /// set what to do when the added data is not allowed by definitions.
+        /// * `check` - Refuse or Allow.
+        pub fn set_child_check(&mut self, check: ChildCheck) {
+            self.child_check = check;
+        }
+
+        /// set check sum algorithm which is used when files are written.
+        /// * `checksum` - check sum algorithm.
+        pub fn set_checksum(&mut self, checksum: ChecksumAlgorithm) {
+            self.checksum = checksum;
+        }
+
+        /// set size over which binary payloads are stored in blob files.
+        /// * `threshold` - byte size.
+        pub fn set_blob_threshold(&mut self, threshold: usize) {
+            self.blob_threshold = threshold;
+        }
+
+        /// get header of the data file which is read last.
+        pub fn get_data_header(&self) -> Option<&FileHeader> {
+            self.data_header.as_ref()
+        }
+
+        /// register codec of user defined type.  
+        /// Definitions whose type is `Type::Custom(code)` use the codec.
+        /// * `code` - type code (0x80 to 0xfe).
+        /// * `codec` - codec.
+        pub fn register_codec(&mut self, code: u8, codec: impl ValueCodec + 'static) -> Result<()> {
+            if !matches!(Type::u8_to_type(code), Ok(Type::Custom(_))) {
+                return Err(FractalError::InvalidCustomCode(code));
+            }
+            let codec: Arc<dyn ValueCodec> = Arc::new(codec);
+            for def in self.def_list.iter_mut().filter(|def| def.data_type == Type::Custom(code)) {
+                def.codec = Codec::new(codec.clone());
+            }
+            self.codecs.insert(code, codec);
+            self.resolved_defs = OnceLock::new();
+            Ok(())
+        }
+
+        /// attach registered codec to definition of user defined type.
+        /// * `def` - definition.
+        fn attach_codec(&self, def: &mut Definition) {
+            if let Type::Custom(code) = def.data_type {
+                if let Some(codec) = self.codecs.get(&code) {
+                    def.codec = Codec::new(codec.clone());
+                }
+            }
+        }
+
         /// add definition
+        /// Return error if other definition has the tag or the name.
         /// * `tags` - definition tag.
         /// * `data_typw` - definition data type.
         /// * `is_multiple` - definition data is able to multiple.
         pub fn add_def(&mut self, tag: u32, name: String, data_type: Type, is_multiple: bool) -> Result<()> {
-            if self.def_list.iter().find(|x| x.tag == tag).is_some() {
-                panic!("tag {:0x} is already defined", tag);
+            self.check_new_def(tag, &name)?;
+            let mut def = Definition::new(tag, name, data_type, is_multiple);
+            self.attach_codec(&mut def);
+            self.def_list.push(def);
+            // The tag may be the base of other definitions.
+            self.resolved_defs = OnceLock::new();
+            Ok(())
+        }
+
+        /// add enumeration definition.
+        /// * `tag` - definition tag.
+        /// * `name` - definition name.
+        /// * `members` - member names. The index is member code.
+        /// * `is_multiple` - definition data is able to multiple.
+        pub fn add_enum_def(&mut self, tag: u32, name: String, members: Vec<String>, is_multiple: bool) -> Result<()> {
+            self.check_new_def(tag, &name)?;
+            let mut def = Definition::new(tag, name, Type::Enum, is_multiple);
+            def.set_members(members)?;
+            self.def_list.push(def);
+            self.resolved_defs = OnceLock::new();
+            Ok(())
+        }
+
+        /// check that no definition has the tag or the name.
+        /// * `tag` - definition tag.
+        /// * `name` - definition name.
+        fn check_new_def(&self, tag: u32, name: &str) -> Result<()> {
+            if self.def_list.iter().any(|x| x.tag == tag) {
+                return Err(FractalError::DuplicateTag(tag));
+            }
+            if self.def_list.iter().any(|x| x.name == name) {
+                return Err(FractalError::DuplicateName(name.to_string()));
             }
-            self.def_list.push(Definition::new(tag, name, data_type, is_multiple));
             Ok(())
         }
 
-        /// get definition
+        /// add vector definition.
+        /// * `tag` - definition tag.
+        /// * `name` - definition name.
+        /// * `dimension` - dimension of vector.
+        /// * `is_multiple` - definition data is able to multiple.
+        pub fn add_vector_def(&mut self, tag: u32, name: String, dimension: u32, is_multiple: bool) -> Result<()> {
+            self.add_def(tag, name, Type::Vector, is_multiple)?;
+            self.get_def_mut(&tag)?.dimension = dimension;
+            Ok(())
+        }
+
+        /// add decimal definition.
+        /// * `tag` - definition tag.
+        /// * `name` - definition name.
+        /// * `scale` - number of digits after the decimal point.
+        /// * `is_multiple` - definition data is able to multiple.
+        pub fn add_decimal_def(&mut self, tag: u32, name: String, scale: u8, is_multiple: bool) -> Result<()> {
+            if scale > MAX_SCALE {
+                return Err(FractalError::InvalidScale(tag));
+            }
+            self.add_def(tag, name, Type::Decimal, is_multiple)?;
+            self.get_def_mut(&tag)?.scale = scale;
+            Ok(())
+        }
+
+        /// remove definition. The tag is removed from the children of all definitions.  
+        /// Return index paths of the data which use the tag (depth-first order). They are removed with their children if migrated.  
+        /// Return error if the tag is the parent tag or other definitions extend it.
+        /// References to the removed data become dangling, and they are reported by `check_references`.
+        /// * `tag` - definition tag.
+        /// * `migration` - what to do with the data.
+        pub fn remove_def(&mut self, tag: &u32, migration: DataMigration) -> Result<Vec<Vec<usize>>> {
+            self.get_declared_def(tag)?;
+            if *tag == self.parent_data.get_def().tag || self.def_list.iter().any(|def| def.base == Some(*tag)) {
+                return Err(FractalError::DefinitionInUse(*tag));
+            }
+            let affected = self.parent_data.find_index_by_tag(*tag);
+            if !self.accept_migration(*tag, &affected, migration)? {
+                return Ok(affected);
+            }
+            // Descendants are removed before their ancestors, so indexes do not move.
+            for index in affected.iter().rev() {
+                self.parent_data.remove_child_by_index(index);
+            }
+            self.def_list.retain(|def| def.tag != *tag);
+            for def in &mut self.def_list {
+                def.children.retain(|child| child.tag != *tag);
+            }
+            self.vector_index_settings.remove(tag);
+            self.spatial_index_tags.remove(tag);
+            self.migrate_data(None)?;
+            Ok(affected)
+        }
+
+        /// rename definition.  
+        /// Return index paths of the data which use the tag (depth-first order).
+        /// Return error if other definition has the name.
+        /// * `tag` - definition tag.
+        /// * `name` - new name.
+        /// * `migration` - what to do with the data.
+        pub fn rename_def(&mut self, tag: &u32, name: String, migration: DataMigration) -> Result<Vec<Vec<usize>>> {
+            self.get_declared_def(tag)?;
+            if self.def_list.iter().any(|def| def.tag != *tag && def.name == name) {
+                return Err(FractalError::DuplicateName(name));
+            }
+            let affected = self.parent_data.find_index_by_tag(*tag);
+            if !self.accept_migration(*tag, &affected, migration)? {
+                return Ok(affected);
+            }
+            self.get_def_mut(tag)?.name = name;
+            self.migrate_data(None)?;
+            Ok(affected)
+        }
+
+        /// change tag of definition.
+        /// The tag is changed in the children and the base of all definitions, and in the references to the same data file.  
+        /// Return index paths of the data which use the tag and the references which pass through it (depth-first order).  
+        /// Return error if the tag is the parent tag or the new tag is already defined.
+        /// References to other data names cannot be migrated because their data is not retagged,
+        /// so they are listed in the dry run and the migration is rejected.
+        /// * `tag` - definition tag.
+        /// * `new_tag` - new tag.
+        /// * `migration` - what to do with the data.
+        pub fn retag_def(&mut self, tag: &u32, new_tag: u32, migration: DataMigration) -> Result<Vec<Vec<usize>>> {
+            self.get_declared_def(tag)?;
+            if *tag == self.parent_data.get_def().tag {
+                return Err(FractalError::DefinitionInUse(*tag));
+            }
+            if self.def_list.iter().any(|def| def.tag == new_tag) {
+                return Err(FractalError::DuplicateTag(new_tag));
+            }
+            let references = self.find_references_to(*tag, new_tag)?;
+            let mut affected = self.parent_data.find_index_by_tag(*tag);
+            affected.extend(references.iter().map(|(index, _)| index.clone()));
+            affected.sort();
+            if !self.accept_migration(*tag, &affected, migration)? {
+                return Ok(affected);
+            }
+            if references.iter().any(|(_, reference)| !self.is_same_data(reference)) {
+                return Err(FractalError::DefinitionInUse(*tag));
+            }
+            for def in &mut self.def_list {
+                if def.tag == *tag {
+                    def.tag = new_tag;
+                }
+                if def.base == Some(*tag) {
+                    def.base = Some(new_tag);
+                }
+                for child in def.children.iter_mut().filter(|child| child.tag == *tag) {
+                    child.tag = new_tag;
+                }
+            }
+            if let Some(distance) = self.vector_index_settings.remove(tag) {
+                self.vector_index_settings.insert(new_tag, distance);
+            }
+            if self.spatial_index_tags.remove(tag) {
+                self.spatial_index_tags.insert(new_tag);
+            }
+            for (index, reference) in references {
+                if let Some(data) = self.parent_data.get_child_mut_by_index(&index) {
+                    data.set_value(reference)?;
+                }
+            }
+            self.migrate_data(Some((*tag, new_tag)))?;
+            Ok(affected)
+        }
+
+        /// decide whether the definition is changed.
+        /// Return false for dry run, and error if the data is rejected.
+        /// * `tag` - definition tag.
+        /// * `affected` - index paths of the affected data.
+        /// * `migration` - what to do with the data.
+        fn accept_migration(&self, tag: u32, affected: &[Vec<usize>], migration: DataMigration) -> Result<bool> {
+            match migration {
+                DataMigration::DryRun => Ok(false),
+                DataMigration::Reject if !affected.is_empty() => Err(FractalError::DefinitionInUse(tag)),
+                _ => Ok(true),
+            }
+        }
+
+        /// find the references which pass through the tag.
+        /// Return index paths and the references whose tag is replaced.
+        /// * `tag` - data tag.
+        /// * `new_tag` - new tag in the references.
+        fn find_references_to(&self, tag: u32, new_tag: u32) -> Result<Vec<(Vec<usize>, Reference)>> {
+            let mut references = Vec::new();
+            for def in self.def_list.iter().filter(|def| def.data_type == Type::Reference) {
+                for index in self.parent_data.find_index_by_tag(def.tag) {
+                    let data = self.parent_data.get_child_by_index(&index).ok_or(FractalError::DataNotFound)?;
+                    if let Some(Value::Reference(mut reference)) = data.get_value()? {
+                        if reference.replace_tag(tag, new_tag) {
+                            references.push((index, reference));
+                        }
+                    }
+                }
+            }
+            Ok(references)
+        }
+
+        /// Return true if the reference points to the data in the same data file.
+        /// * `reference` - reference to data.
+        fn is_same_data(&self, reference: &Reference) -> bool {
+            reference.data_name.as_ref().is_none_or(|name| *name == self.path_manager.data_name)
+        }
+
+        /// replace definitions of all data by the current definitions, and rebuild indexes.
+        /// * `retagged` - old tag and new tag of the retagged definition.
+        fn migrate_data(&mut self, retagged: Option<(u32, u32)>) -> Result<()> {
+            self.resolved_defs = OnceLock::new();
+            let mut defs = HashMap::new();
+            for def in &self.def_list {
+                defs.insert(def.tag, self.get_def(&def.tag)?.clone());
+            }
+            // The parent data keeps its own definition.
+            defs.remove(&self.parent_data.get_def().tag);
+            if let Some((tag, new_tag)) = retagged {
+                if let Some(def) = defs.get(&new_tag).cloned() {
+                    defs.insert(tag, def);
+                }
+            }
+            self.parent_data.replace_defs(&defs);
+            self.build_vector_indexes()?;
+            self.build_spatial_indexes()
+        }
+
+        /// get effective definition.  
+        /// The definition which extends base definition inherits it.
         /// * `tags` - definition tag.
         pub fn get_def(&self, tag: &u32) -> Result<&Definition>{
+            if let Some(def) = self.resolved_defs.get_or_init(|| self.resolve_defs()).get(tag) {
+                return Ok(def);
+            }
+            self.get_declared_def(tag)
+        }
+
+        /// get definition as declared. It does not inherit base definition.
+        /// * `tags` - definition tag.
+        pub fn get_declared_def(&self, tag: &u32) -> Result<&Definition>{
             let d = self.def_list.iter().find(|x| x.tag == *tag);
             match d {
                 Some(def) => Ok(def),
-                None => {panic!("tag {:0x} is not defined", tag);},
+                None => Err(FractalError::UnknownTag(*tag)),
             }
         }
 
-        /// get definition by mut
-        pub fn get_def_mut(&mut self, tag: &u32) -> Option<&mut Definition>{
-            self.def_list.iter_mut().find(|x| x.tag == *tag)
+        /// get declared definition by mut.  
+        /// Effective definitions are resolved again when a definition is got.
+        pub fn get_def_mut(&mut self, tag: &u32) -> Result<&mut Definition>{
+            self.resolved_defs = OnceLock::new();
+            let d = self.def_list.iter_mut().find(|x| x.tag == *tag);
+            match d {
+                Some(def) => Ok(def),
+                None => Err(FractalError::UnknownTag(*tag)),
+            }
+        }
+
+        /// set base definition which the definition extends.
+        /// Return error if the base is not base definition, its type is different or it extends the definition.
+        /// * `tag` - definition tag.
+        /// * `base` - base definition tag. None removes the inheritance.
+        pub fn set_base(&mut self, tag: &u32, base: Option<u32>) -> Result<()> {
+            let data_type = self.get_declared_def(tag)?.data_type;
+            if let Some(base_tag) = base {
+                let base_def = self.get_declared_def(&base_tag)?;
+                if !base_def.is_base {
+                    return Err(FractalError::NotBase(base_tag));
+                }
+                if base_def.data_type != data_type {
+                    return Err(FractalError::TypeMismatch { tag: *tag, expected: base_def.data_type });
+                }
+                let mut visited = Vec::new();
+                let mut next = Some(base_tag);
+                while let Some(t) = next.filter(|t| !visited.contains(t)) {
+                    if t == *tag {
+                        return Err(FractalError::CyclicDefinition(*tag));
+                    }
+                    visited.push(t);
+                    next = self.get_declared_def(&t).ok().and_then(|def| def.base);
+                }
+            }
+            self.get_def_mut(tag)?.base = base;
+            Ok(())
+        }
+
+        /// resolve effective definitions of the tags which extend base definitions.  
+        /// The definition whose base is unknown or cyclic is used as declared.
+        fn resolve_defs(&self) -> HashMap<u32, Definition> {
+            let mut resolved = HashMap::new();
+            for def in self.def_list.iter().filter(|def| def.base.is_some()) {
+                if let Some(effective) = self.resolve_def(def.tag, &mut Vec::new()) {
+                    resolved.insert(def.tag, effective);
+                }
+            }
+            resolved
+        }
+
+        /// resolve effective definition.
+        /// * `tag` - definition tag.
+        /// * `descendants` - tags which extend the definition.
+        fn resolve_def(&self, tag: u32, descendants: &mut Vec<u32>) -> Option<Definition> {
+            if descendants.contains(&tag) {
+                return None;
+            }
+            let def = self.def_list.iter().find(|def| def.tag == tag)?;
+            match def.base {
+                Some(base) => {
+                    descendants.push(tag);
+                    let base = self.resolve_def(base, descendants)?;
+                    Some(def.inherit(&base))
+                },
+                None => Some(def.clone()),
+            }
         }
 
         /// get defined tag list.
@@ -86,30 +555,304 @@ pub mod manager {
             vec
         }
 
-        /// add child in definition.
+        /// add child in definition. Nothing is changed if the definition already has the child.  
+        /// Return error if the tags are unknown or the child makes a cycle without recursive definition.
+        /// * `tag` - parent definition tag.
+        /// * `child_tag` - child definition tag.
+        pub fn add_def_child(&mut self, tag: &u32, child_tag: u32) -> Result<()> {
+            self.get_declared_def(tag)?;
+            self.get_declared_def(&child_tag)?;
+            self.check_def_cycle(*tag, child_tag)?;
+            let def = self.get_def_mut(tag)?;
+            if !def.children.iter().any(|child| child.tag == child_tag) {
+                def.children.push(ChildDef::new(child_tag));
+            }
+            Ok(())
+        }
+
+        /// set cardinality of child tag. The child tag is added if the definition does not have it.  
+        /// Return error if the child makes a cycle without recursive definition.
         /// * `tag` - parent definition tag.
         /// * `child_tag` - child definition tag.
-        pub fn add_def_child(&mut self, tag: &u32, child_tag: u32) -> bool {
-            for child_def in &self.def_list {
-                if child_def.tag != child_tag {
+        /// * `min` - min number of the child data.
+        /// * `max` - max number of the child data. None is unbounded.
+        pub fn set_child_range(&mut self, tag: &u32, child_tag: u32, min: u32, max: Option<u32>) -> Result<()> {
+            self.get_def(&child_tag)?;
+            if max.is_some_and(|max| min > max) {
+                return Err(FractalError::InvalidCardinality { parent: *tag, child: child_tag });
+            }
+            self.check_def_cycle(*tag, child_tag)?;
+            let def = self.get_def_mut(tag)?;
+            let child = ChildDef::with_range(child_tag, min, max);
+            match def.children.iter_mut().find(|e| e.tag == child_tag) {
+                Some(e) => *e = child,
+                None => def.children.push(child),
+            }
+            Ok(())
+        }
+
+        /// find all issues of definitions.  
+        /// Dangling child tags, cycles without recursive definition, unreachable definitions and duplicate names are reported.
+        /// A definition is unreachable if it is neither a descendant nor a base of top level definitions.
+        /// Top level definitions are the children of the parent definition. If the parent definition has no children,
+        /// top level data can have any tag, so every definition which is not a child of other definitions is top level.
+        /// The definitions in a cycle are reported only as cyclic.
+        pub fn check_definitions(&self) -> Vec<FractalError> {
+            let mut issues = Vec::new();
+            for def in &self.def_list {
+                for child_tag in def.get_child_tags() {
+                    if self.get_declared_def(&child_tag).is_err() {
+                        issues.push(FractalError::DanglingChild { parent: def.tag, child: child_tag });
+                    }
+                }
+            }
+            let mut cyclic = HashSet::new();
+            for def in &self.def_list {
+                let children = self.get_def(&def.tag).map(|e| e.get_child_tags()).unwrap_or_default();
+                if children.iter().any(|child| self.check_def_cycle(def.tag, *child).is_err()) {
+                    issues.push(FractalError::CyclicDefinition(def.tag));
+                    cyclic.insert(def.tag);
+                }
+            }
+            let parent_tag = self.parent_data.get_def().tag;
+            let mut next: Vec<u32> = if self.is_any_top_level() {
+                let mut is_child = HashSet::new();
+                for def in &self.def_list {
+                    let children = self.get_def(&def.tag).map(|e| e.get_child_tags()).unwrap_or_default();
+                    is_child.extend(children.into_iter().filter(|child| *child != def.tag));
+                }
+                self.def_list.iter().map(|def| def.tag).filter(|tag| !is_child.contains(tag)).collect()
+            }
+            else {
+                vec![parent_tag]
+            };
+            let mut reached: HashSet<u32> = HashSet::new();
+            while let Some(tag) = next.pop() {
+                if reached.insert(tag) {
+                    next.extend(self.get_def(&tag).map(|e| e.get_child_tags()).unwrap_or_default());
+                    next.extend(self.get_declared_def(&tag).ok().and_then(|e| e.base));
+                }
+            }
+            for def in self.def_list.iter().filter(|def| !reached.contains(&def.tag) && !cyclic.contains(&def.tag)) {
+                issues.push(FractalError::UnreachableDefinition(def.tag));
+            }
+            let mut names: Vec<&String> = Vec::new();
+            for def in &self.def_list {
+                if names.contains(&&def.name) {
+                    issues.push(FractalError::DuplicateName(def.name.clone()));
+                }
+                names.push(&def.name);
+            }
+            issues
+        }
+
+        /// Return true if top level data can have any tag, that is, the parent definition has no children.
+        fn is_any_top_level(&self) -> bool {
+            self.get_declared_def(&self.parent_data.get_def().tag).map(|def| def.children.is_empty()).unwrap_or(true)
+        }
+
+        /// refuse or warn the issues of definitions.  
+        /// Return the first issue if definition check is refuse, otherwise keep the issues as warnings.
+        fn report_def_issues(&mut self) -> Result<()> {
+            let mut issues = self.check_definitions();
+            match self.definition_check {
+                DefinitionCheck::Refuse if !issues.is_empty() => Err(issues.swap_remove(0)),
+                DefinitionCheck::Refuse => Ok(()),
+                DefinitionCheck::Warn => {
+                    self.warnings.extend(issues);
+                    Ok(())
+                },
+            }
+        }
+
+        /// check that the child does not make a cycle of definitions.  
+        /// The cycle which has a recursive definition is allowed, and its depth is limited by the max depth.
+        /// * `tag` - parent definition tag.
+        /// * `child_tag` - child definition tag.
+        fn check_def_cycle(&self, tag: u32, child_tag: u32) -> Result<()> {
+            if self.get_def(&tag).is_ok_and(|def| def.is_recursive) {
+                return Ok(());
+            }
+            let mut visited = Vec::new();
+            let mut next = vec![child_tag];
+            while let Some(t) = next.pop() {
+                if t == tag {
+                    return Err(FractalError::CyclicDefinition(tag));
+                }
+                if visited.contains(&t) {
                     continue;
                 }
-                match self.get_def_mut(tag) {
-                    Some(def) => {
-                        def.children.push(child_tag);
-                        return true;
-                    },
-                    None => { return false; }
+                visited.push(t);
+                match self.get_def(&t) {
+                    Ok(def) if !def.is_recursive => next.extend(def.get_child_tags()),
+                    _ => continue,
+                }
+            }
+            Ok(())
+        }
+
+        /// make data of the tag from definitions.  
+        /// The data has default value, and required children (min number of child data) are added recursively.
+        /// Nullable data without default value is null.
+        /// * `tag` - definition tag.
+        pub fn instantiate(&self, tag: &u32) -> Result<Data> {
+            self.instantiate_data(*tag, &mut Vec::new())
+        }
+
+        /// make data of the tag from definitions with the value.  
+        /// Required children are added in the same way as `instantiate`.
+        /// * `tag` - definition tag.
+        /// * `value` - data value.
+        pub fn instantiate_with(&self, tag: &u32, value: impl Into<Value>) -> Result<Data> {
+            let mut data = Data::new(self.get_def(tag)?.clone(), value)?;
+            self.add_required_children(&mut data, &mut vec![*tag])?;
+            Ok(data)
+        }
+
+        /// make data which has default value and required children.
+        /// * `tag` - definition tag.
+        /// * `ancestors` - tags of the data which are being made.
+        fn instantiate_data(&self, tag: u32, ancestors: &mut Vec<u32>) -> Result<Data> {
+            if ancestors.contains(&tag) {
+                return Err(FractalError::CyclicDefinition(tag));
+            }
+            let def = self.get_def(&tag)?;
+            let mut data = match &def.default_value {
+                Some(value) => Data::read_binary(def.clone(), value.clone()),
+                None if def.is_nullable => Data::null(def.clone())?,
+                None => return Err(FractalError::NoDefault(tag)),
+            };
+            ancestors.push(tag);
+            self.add_required_children(&mut data, ancestors)?;
+            ancestors.pop();
+            Ok(data)
+        }
+
+        /// add required children in the data.
+        /// * `data` - parent data.
+        /// * `ancestors` - tags of the data which are being made. The last tag is the parent.
+        fn add_required_children(&self, data: &mut Data, ancestors: &mut Vec<u32>) -> Result<()> {
+            for child in &self.get_def(&data.get_def().tag)?.children {
+                for _ in 0..child.min {
+                    data.add_child(&[], self.instantiate_data(child.tag, ancestors)?)?;
                 }
             }
-            false
+            Ok(())
         }
 
-        /// add child data.
+        /// add child data.  
+        /// The max number of the child data is checked, but the min number is checked by `validate`.
+        /// Values of the data and its descendants are checked by the constraints of definitions.  
+        /// The depth of the data and its descendants is checked by the max depth of definitions and `MAX_DATA_DEPTH`.
         /// * `data` - added data.
         /// * `root` - child data root.
-        pub fn add_child(&mut self, data: Data, root: &mut DataRoot) {
-            self.parent_data.add_child(root, data);
+        pub fn add_child(&mut self, data: Data, root: &DataRoot) -> Result<()> {
+            self.check_data_constraints(&data)?;
+            let mut ancestors = Vec::new();
+            for i in 1..=root.len() {
+                let ancestor = self.parent_data.get_child(&root[..i]).ok_or(FractalError::DataNotFound)?;
+                ancestors.push(ancestor.get_def().tag);
+            }
+            self.check_data_depth(&data, &mut ancestors)?;
+            if self.child_check == ChildCheck::Refuse {
+                let parent = self.parent_data.get_child(root).ok_or(FractalError::DataNotFound)?;
+                self.check_child(parent.get_def().tag, parent.get_children(), &data)?;
+            }
+            self.parent_data.add_child(root, data)?;
+            // Indexes do not have the added data, so they are built again on the next search.
+            self.vector_indexes = OnceLock::new();
+            self.spatial_indexes = OnceLock::new();
+            Ok(())
+        }
+
+        /// check that the data and its descendants are allowed by definitions.  
+        /// Top level data can have any tag if the parent definition has no children.
+        /// * `parent_tag` - tag of the parent data.
+        /// * `siblings` - children of the parent data.
+        /// * `data` - added data.
+        fn check_child(&self, parent_tag: u32, siblings: &[Data], data: &Data) -> Result<()> {
+            let tag = data.get_def().tag;
+            let child_def = self.get_def(&parent_tag)?.get_child(tag);
+            if (parent_tag != self.parent_data.get_def().tag || !self.is_any_top_level()) && child_def.is_none() {
+                return Err(FractalError::ChildNotAllowed { parent: parent_tag, child: tag });
+            }
+            let count = siblings.iter().filter(|e| e.get_def().tag == tag).count() + 1;
+            if !self.get_def(&tag)?.is_multiple && count > 1 {
+                return Err(FractalError::DuplicateChild { parent: parent_tag, child: tag });
+            }
+            if child_def.is_some_and(|child| child.max.is_some_and(|max| count > max as usize)) {
+                return Err(FractalError::Cardinality { parent: parent_tag, child: tag, count });
+            }
+            let children = data.get_children();
+            for (i, child) in children.iter().enumerate() {
+                self.check_child(tag, &children[..i], child)?;
+            }
+            Ok(())
+        }
+
+        /// find all data which break the children, multiplicity and cardinality of definitions.
+        /// Return violations in depth-first order.
+        pub fn validate(&self) -> Vec<Violation> {
+            let mut violations = Vec::new();
+            self.validate_data(&self.parent_data, &mut Vec::new(), &mut violations);
+            violations
+        }
+
+        /// find violations in the data and its descendants.
+        /// * `data` - validated data.
+        /// * `index` - index path of the data from the parent data.
+        /// * `violations` - found violations.
+        fn validate_data(&self, data: &Data, index: &mut Vec<usize>, violations: &mut Vec<Violation>) {
+            let tag = data.get_def().tag;
+            let def = match self.get_def(&tag) {
+                Ok(def) => def,
+                Err(e) => return violations.push(self.violation(index, e)),
+            };
+            let children = data.get_children();
+            // Top level data can have any tag if the parent definition has no children.
+            if !index.is_empty() || !self.is_any_top_level() {
+                for (i, child) in children.iter().enumerate() {
+                    let child_tag = child.get_def().tag;
+                    if def.get_child(child_tag).is_none() {
+                        index.push(i);
+                        violations.push(self.violation(index, FractalError::ChildNotAllowed { parent: tag, child: child_tag }));
+                        index.pop();
+                    }
+                }
+            }
+            let mut tags: Vec<u32> = def.get_child_tags();
+            for child in children {
+                if !tags.contains(&child.get_def().tag) {
+                    tags.push(child.get_def().tag);
+                }
+            }
+            for child_tag in tags {
+                let count = children.iter().filter(|e| e.get_def().tag == child_tag).count();
+                let is_multiple = self.get_def(&child_tag).map(|e| e.is_multiple).unwrap_or(true);
+                if !is_multiple && count > 1 {
+                    violations.push(self.violation(index, FractalError::DuplicateChild { parent: tag, child: child_tag }));
+                }
+                else if def.get_child(child_tag).is_some_and(|child| !child.contains(count)) {
+                    let e = FractalError::Cardinality { parent: tag, child: child_tag, count };
+                    violations.push(self.violation(index, e));
+                }
+            }
+            for (i, child) in children.iter().enumerate() {
+                index.push(i);
+                self.validate_data(child, index, violations);
+                index.pop();
+            }
+        }
+
+        /// make violation of the data.
+        /// * `index` - index path of the data from the parent data.
+        /// * `error` - broken constraint.
+        fn violation(&self, index: &[usize], error: FractalError) -> Violation {
+            Violation {
+                index: index.to_vec(),
+                root: self.parent_data.index_to_root(index),
+                error,
+            }
         }
 
         /// get data.
@@ -118,51 +861,537 @@ pub mod manager {
             self.parent_data.get_child(root)
         }
 
+        /// get data by index path. It is used with the index of `Violation` and `Neighbor`.
+        /// * `index` - index path from the parent data.
+        pub fn get_data_by_index(&self, index: &[usize]) -> Option<&Data> {
+            self.parent_data.get_child_by_index(index)
+        }
+
+        /// find all data of the tag whose value is in the range.  
+        /// Values are compared by `Value::partial_cmp`, so temporal values are compared chronologically.
+        /// * `tag` - data tag.
+        /// * `range` - range of value.
+        pub fn find_range(&self, tag: &u32, range: impl RangeBounds<Value>) -> Result<Vec<&Data>> {
+            let def = self.get_def(tag)?;
+            if let Type::Custom(code) = def.data_type {
+                return match def.codec.get() {
+                    Some(codec) => self.find_custom_range(tag, codec, range),
+                    None => Err(FractalError::UnregisteredCodec(code)),
+                };
+            }
+            let mut vec = Vec::new();
+            for data in self.parent_data.find_by_tag(*tag) {
+                // Null is not in any range.
+                if let Some(value) = data.get_value()? {
+                    if range.contains(&value) {
+                        vec.push(data);
+                    }
+                }
+            }
+            Ok(vec)
+        }
+
+        /// find all data of the parent tag whose child of the tag is in the state.  
+        /// The parent which has multiple children may be found in multiple states.
+        /// * `parent_tag` - tag of parent data.
+        /// * `tag` - tag of child data.
+        /// * `presence` - state of child data.
+        pub fn find_by_presence(&self, parent_tag: &u32, tag: &u32, presence: Presence) -> Result<Vec<&Data>> {
+            self.get_def(parent_tag)?;
+            self.get_def(tag)?;
+            let mut parents = self.parent_data.find_by_tag(*parent_tag);
+            if *parent_tag == self.parent_data.get_def().tag {
+                parents.insert(0, &self.parent_data);
+            }
+            Ok(parents.into_iter().filter(|parent| {
+                let mut children = parent.get_children().iter().filter(|child| child.get_def().tag == *tag).peekable();
+                match presence {
+                    Presence::Value => children.any(|child| !child.is_null()),
+                    Presence::Null => children.any(|child| child.is_null()),
+                    Presence::Missing => children.peek().is_none(),
+                }
+            }).collect())
+        }
+
+        /// find all data of user defined type whose value is in the range.  
+        /// Values are compared by the codec.
+        /// * `tag` - data tag.
+        /// * `codec` - codec of the type.
+        /// * `range` - range of value.
+        fn find_custom_range(&self, tag: &u32, codec: &dyn ValueCodec, range: impl RangeBounds<Value>) -> Result<Vec<&Data>> {
+            let encode = |bound: Bound<&Value>| -> Result<Bound<Vec<u8>>> {
+                Ok(match bound {
+                    Bound::Included(value) => Bound::Included(codec.encode(value)?),
+                    Bound::Excluded(value) => Bound::Excluded(codec.encode(value)?),
+                    Bound::Unbounded => Bound::Unbounded,
+                })
+            };
+            let start = encode(range.start_bound())?;
+            let end = encode(range.end_bound())?;
+            let mut vec = Vec::new();
+            for data in self.parent_data.find_by_tag(*tag) {
+                // Null is not in any range.
+                if data.is_null() {
+                    continue;
+                }
+                let value = data.get_bite_value();
+                let after_start = match &start {
+                    Bound::Included(start) => codec.compare(value, start).is_some_and(|o| o.is_ge()),
+                    Bound::Excluded(start) => codec.compare(value, start).is_some_and(|o| o.is_gt()),
+                    Bound::Unbounded => true,
+                };
+                let before_end = match &end {
+                    Bound::Included(end) => codec.compare(value, end).is_some_and(|o| o.is_le()),
+                    Bound::Excluded(end) => codec.compare(value, end).is_some_and(|o| o.is_lt()),
+                    Bound::Unbounded => true,
+                };
+                if after_start && before_end {
+                    vec.push(data);
+                }
+            }
+            Ok(vec)
+        }
+
+        /// resolve reference to data.  
+        /// The data in other data name is read from its data file.  
+        /// Return error if the reference points to no data or more than one data.
+        /// * `reference` - reference to data.
+        pub fn resolve(&self, reference: &Reference) -> Result<Data> {
+            self.resolve_with(reference, &mut HashMap::new())
+        }
+
+        /// resolve reference to data.
+        /// * `reference` - reference to data.
+        /// * `others` - managers of other data names which are already read.
+        fn resolve_with(&self, reference: &Reference, others: &mut HashMap<String, Manager>) -> Result<Data> {
+            let parent = match &reference.data_name {
+                Some(name) if *name != self.path_manager.data_name => {
+                    if !others.contains_key(name) {
+                        others.insert(name.clone(), self.read_other_data(name)?);
+                    }
+                    &others[name].parent_data
+                },
+                _ => &self.parent_data,
+            };
+            // Each step must select exactly one data, otherwise the reference points to other data
+            // when the order of data is changed.
+            let mut data = parent;
+            for step in &reference.path {
+                let key = match &step.key {
+                    Some((tag, value)) => Some(Data::read_binary(self.get_def(tag)?.clone(), value.clone())),
+                    None => None,
+                };
+                let mut selected = data.get_children().iter().filter(|child| child.get_def().tag == step.tag
+                    && key.as_ref().is_none_or(|key| child.is_equal_child(key)));
+                data = selected.next().ok_or_else(|| FractalError::DanglingReference(reference.to_string()))?;
+                if selected.next().is_some() {
+                    return Err(FractalError::AmbiguousReference(reference.to_string()));
+                }
+            }
+            Ok(data.clone())
+        }
+
+        /// read data of other data name in the same table.  
+        /// References in the data are not checked, and the warnings of the data are not kept.
+        /// * `data_name` - data name.
+        fn read_other_data(&self, data_name: &str) -> Result<Manager> {
+            let pm = &self.path_manager;
+            let mut manager = Manager::new(&pm.dir_path, pm.table_name.clone(), data_name.to_string());
+            manager.def_list = self.def_list.clone();
+            manager.definition_check = self.definition_check;
+            manager.read_data_file()?;
+            Ok(manager)
+        }
+
+        /// check that all references point to data.
+        pub fn check_references(&self) -> Result<()> {
+            let mut others = HashMap::new();
+            for def in self.def_list.iter().filter(|def| def.data_type == Type::Reference) {
+                for data in self.parent_data.find_by_tag(def.tag) {
+                    if let Some(Value::Reference(reference)) = data.get_value()? {
+                        self.resolve_with(&reference, &mut others)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        /// Check that all data values satisfy the constraints of definitions.
+        pub fn check_constraints(&self) -> Result<()> {
+            for child in self.parent_data.get_children() {
+                self.check_data_constraints(child)?;
+            }
+            Ok(())
+        }
+
+        /// Check that the data and its descendants satisfy the constraints of definitions.
+        /// * `data` - checked data.
+        fn check_data_constraints(&self, data: &Data) -> Result<()> {
+            let def = self.get_def(&data.get_def().tag)?;
+            if !def.constraints.is_empty() {
+                if let Some(value) = data.get_value()? {
+                    def.constraints.check(def.tag, &value)?;
+                }
+            }
+            for child in data.get_children() {
+                self.check_data_constraints(child)?;
+            }
+            Ok(())
+        }
+
+        /// Check that all data are not nested deeper than the max depth of definitions.
+        pub fn check_depths(&self) -> Result<()> {
+            for child in self.parent_data.get_children() {
+                self.check_data_depth(child, &mut Vec::new())?;
+            }
+            Ok(())
+        }
+
+        /// Check that the data and its descendants are not nested deeper than the max depth of definitions and `MAX_DATA_DEPTH`.
+        /// * `data` - checked data.
+        /// * `ancestors` - tags of the ancestors from top level data.
+        fn check_data_depth(&self, data: &Data, ancestors: &mut Vec<u32>) -> Result<()> {
+            let tag = data.get_def().tag;
+            ancestors.push(tag);
+            let depth = ancestors.iter().filter(|t| **t == tag).count();
+            let max = self.get_def(&tag).ok().and_then(|def| def.max_depth);
+            if max.is_some_and(|max| depth > max as usize) {
+                return Err(FractalError::DepthExceeded { tag, depth });
+            }
+            if ancestors.len() > MAX_DATA_DEPTH {
+                return Err(FractalError::DepthExceeded { tag, depth: ancestors.len() });
+            }
+            for child in data.get_children() {
+                self.check_data_depth(child, ancestors)?;
+            }
+            ancestors.pop();
+            Ok(())
+        }
+
+        /// enable approximate nearest neighbour index of the tag.  
+        /// The index is built now and rebuilt on `read_data`, or on the next search after data is added.
+        /// * `tag` - tag of vector data.
+        /// * `distance` - distance which is used by search.
+        pub fn set_vector_index(&mut self, tag: u32, distance: Distance) -> Result<()> {
+            if self.get_def(&tag)?.data_type != Type::Vector {
+                return Err(FractalError::TypeMismatch { tag, expected: Type::Vector });
+            }
+            self.vector_index_settings.insert(tag, distance);
+            self.build_vector_indexes()
+        }
+
+        /// build all of vector indexes.
+        pub fn build_vector_indexes(&mut self) -> Result<()> {
+            self.vector_indexes = OnceLock::from(self.make_vector_indexes()?);
+            Ok(())
+        }
+
+        /// get vector indexes. They are built if data is added after they are built.
+        fn get_vector_indexes(&self) -> Result<&HashMap<u32, VectorIndex>> {
+            if let Some(indexes) = self.vector_indexes.get() {
+                return Ok(indexes);
+            }
+            let indexes = self.make_vector_indexes()?;
+            Ok(self.vector_indexes.get_or_init(|| indexes))
+        }
+
+        /// make vector indexes of the tags which are set by `set_vector_index`.
+        fn make_vector_indexes(&self) -> Result<HashMap<u32, VectorIndex>> {
+            let mut indexes = HashMap::new();
+            for (tag, distance) in &self.vector_index_settings {
+                let mut entries = Vec::new();
+                for index in self.parent_data.find_index_by_tag(*tag) {
+                    if let Some(data) = self.parent_data.get_child_by_index(&index) {
+                        if let Some(vector) = self.get_vector(data)? {
+                            entries.push((index, vector));
+                        }
+                    }
+                }
+                indexes.insert(*tag, VectorIndex::build(*distance, entries));
+            }
+            Ok(indexes)
+        }
+
+        /// find k nearest data of the tag.  
+        /// If the vector index of the distance is built, the result is approximate.
+        /// * `tag` - tag of vector data.
+        /// * `query` - query vector.
+        /// * `k` - number of results.
+        /// * `distance` - distance between vectors.
+        pub fn nearest(&self, tag: &u32, query: &[f32], k: usize, distance: Distance) -> Result<Vec<Neighbor>> {
+            self.check_query(tag, query)?;
+            match self.get_vector_indexes()?.get(tag) {
+                Some(index) if index.get_distance() == distance => {
+                    self.to_neighbors(index.search(query, k))
+                },
+                _ => self.nearest_exact(tag, query, k, distance),
+            }
+        }
+
+        /// find k nearest data of the tag by scanning all data.
+        /// * `tag` - tag of vector data.
+        /// * `query` - query vector.
+        /// * `k` - number of results.
+        /// * `distance` - distance between vectors.
+        pub fn nearest_exact(&self, tag: &u32, query: &[f32], k: usize, distance: Distance) -> Result<Vec<Neighbor>> {
+            self.check_query(tag, query)?;
+            let mut found = Vec::new();
+            for index in self.parent_data.find_index_by_tag(*tag) {
+                if let Some(data) = self.parent_data.get_child_by_index(&index) {
+                    if let Some(vector) = self.get_vector(data)? {
+                        found.push((index, distance.between(&vector, query)));
+                    }
+                }
+            }
+            found.sort_by(|a, b| a.1.total_cmp(&b.1));
+            found.truncate(k);
+            self.to_neighbors(found)
+        }
+
+        /// check tag and dimension of query vector.
+        /// * `tag` - tag of vector data.
+        /// * `query` - query vector.
+        fn check_query(&self, tag: &u32, query: &[f32]) -> Result<()> {
+            let def = self.get_def(tag)?;
+            if def.data_type != Type::Vector {
+                return Err(FractalError::TypeMismatch { tag: *tag, expected: Type::Vector });
+            }
+            if query.len() != def.dimension as usize {
+                return Err(FractalError::DimensionMismatch {
+                    tag: *tag, expected: def.dimension as usize, actual: query.len()
+                });
+            }
+            Ok(())
+        }
+
+        /// get vector value of data.
+        /// Return None if the value is null.
+        /// * `data` - vector data.
+        fn get_vector(&self, data: &Data) -> Result<Option<Vec<f32>>> {
+            match data.get_value()? {
+                Some(Value::Vector(vector)) => Ok(Some(vector)),
+                None => Ok(None),
+                _ => Err(FractalError::TypeMismatch { tag: data.get_def().tag, expected: Type::Vector }),
+            }
+        }
+
+        /// Convert data index and distance to neighbors.
+        /// * `found` - data index and distance.
+        fn to_neighbors(&self, found: Vec<(Vec<usize>, f32)>) -> Result<Vec<Neighbor>> {
+            let mut vec = Vec::new();
+            for (index, distance) in found {
+                if self.parent_data.get_child_by_index(&index).is_none() {
+                    return Err(FractalError::DataNotFound);
+                }
+                let root = self.parent_data.index_to_root(&index);
+                vec.push(Neighbor { index, root, distance });
+            }
+            Ok(vec)
+        }
+
+        /// enable R-tree index of the tag.  
+        /// The index is built now and rebuilt on `read_data`, or on the next search after data is added.
+        /// * `tag` - tag of point data.
+        pub fn set_spatial_index(&mut self, tag: u32) -> Result<()> {
+            self.check_point_tag(&tag)?;
+            self.spatial_index_tags.insert(tag);
+            self.build_spatial_indexes()
+        }
+
+        /// build all of spatial indexes.
+        pub fn build_spatial_indexes(&mut self) -> Result<()> {
+            self.spatial_indexes = OnceLock::from(self.make_spatial_indexes()?);
+            Ok(())
+        }
+
+        /// get spatial indexes. They are built if data is added after they are built.
+        fn get_spatial_indexes(&self) -> Result<&HashMap<u32, RTree>> {
+            if let Some(indexes) = self.spatial_indexes.get() {
+                return Ok(indexes);
+            }
+            let indexes = self.make_spatial_indexes()?;
+            Ok(self.spatial_indexes.get_or_init(|| indexes))
+        }
+
+        /// make spatial indexes of the tags which are set by `set_spatial_index`.
+        fn make_spatial_indexes(&self) -> Result<HashMap<u32, RTree>> {
+            let mut indexes = HashMap::new();
+            for tag in &self.spatial_index_tags {
+                let mut entries = Vec::new();
+                for index in self.parent_data.find_index_by_tag(*tag) {
+                    match self.parent_data.get_child_by_index(&index) {
+                        Some(data) if !data.is_null() => entries.push((index, data.get::<Point>()?)),
+                        _ => continue,
+                    }
+                }
+                indexes.insert(*tag, RTree::build(entries));
+            }
+            Ok(indexes)
+        }
+
+        /// find all data of the tag whose point is in the box (depth-first order).
+        /// * `tag` - tag of point data.
+        /// * `area` - bounding box.
+        pub fn find_in_box(&self, tag: &u32, area: &BoundingBox) -> Result<Vec<&Data>> {
+            self.find_points(tag, area, |_| true)
+        }
+
+        /// find all data of the tag whose point is within the radius (depth-first order).
+        /// * `tag` - tag of point data.
+        /// * `center` - center of circle.
+        /// * `radius` - radius of circle (meters).
+        pub fn find_in_radius(&self, tag: &u32, center: &Point, radius: f64) -> Result<Vec<&Data>> {
+            self.find_points(tag, &BoundingBox::around(center, radius), |point| center.distance(point) <= radius)
+        }
+
+        /// find all data of the tag whose point is in the box and satisfies the filter.
+        /// * `tag` - tag of point data.
+        /// * `area` - bounding box.
+        /// * `filter` - filter of point.
+        fn find_points(&self, tag: &u32, area: &BoundingBox, filter: impl Fn(&Point) -> bool) -> Result<Vec<&Data>> {
+            self.check_point_tag(tag)?;
+            let mut candidates = match self.get_spatial_indexes()?.get(tag) {
+                Some(index) => index.search(area),
+                None => self.parent_data.find_index_by_tag(*tag),
+            };
+            candidates.sort();
+            let mut vec = Vec::new();
+            for index in candidates {
+                if let Some(data) = self.parent_data.get_child_by_index(&index).filter(|data| !data.is_null()) {
+                    let point = data.get::<Point>()?;
+                    if area.contains(&point) && filter(&point) {
+                        vec.push(data);
+                    }
+                }
+            }
+            Ok(vec)
+        }
+
+        /// check that the tag is defined as point.
+        /// * `tag` - tag of point data.
+        fn check_point_tag(&self, tag: &u32) -> Result<()> {
+            if self.get_def(tag)?.data_type != Type::Point {
+                return Err(FractalError::TypeMismatch { tag: *tag, expected: Type::Point });
+            }
+            Ok(())
+        }
+
         /// write definition on file.
         pub fn write_def(&self) -> Result<()> {
             let mut b = BinaryManager::new();
+            b.set_checksum(self.checksum);
             for def in &self.def_list{
-                // Exclude base definition.
-                if def.is_base() {
-                    continue;
-                }
+                b.create_child(Mode::Write)?;
+                self.write_def_fields(def, b.get_child()?)?;
+                // STX, length, ETX, Check sum
+                b.end_child_and_add_data()?;
+            }
+            let header = FileHeader::new(FileKind::Definition, None);
+            b.write(&self.path_manager.get_def_path()?, &header)?;
+            Ok(())
+        }
 
-                b.create_child(Mode::Write);
-                // STX
-                b.get_child()?.add_start_data();
-                // Tag
-                b.get_child()?.add_u32(def.tag)?;
+        /// write fields of definition.
+        /// * `def` - written definition.
+        /// * `b` - binary data manager.
+        fn write_def_fields(&self, def: &Definition, b: &mut BinaryManager) -> Result<()> {
+            // Tag
+            b.add_u32(def.tag)?;
 
-                b.get_child()?.add_str(&def.name)?;
-                // Data type
-                b.get_child()?.add_u8(def.get_type_num());
-                // Is multiple
-                b.get_child()?.add_bool(def.is_multiple);
-                // Explanation
-                b.get_child()?.add_str(&def.explanation)?;
-                // Children tags.
-                b.get_child()?.add_usize(def.children.len())?;
-                for child in &def.children {
-                    b.get_child()?.add_u32(*child)?;
+            b.add_str(&def.name)?;
+            // Data type
+            b.add_u8(def.get_type_num());
+            // Flags (bit 0: is multiple, bit 1: is nullable, bit 2: is base, bit 3: is recursive)
+            b.add_u8(u8::from(def.is_multiple) | u8::from(def.is_nullable) << 1 | u8::from(def.is_base) << 2
+                | u8::from(def.is_recursive) << 3);
+            // Explanation
+            b.add_str(&def.explanation)?;
+            // Children tags and cardinality (max u32::MAX is unbounded).
+            b.add_usize(def.children.len())?;
+            for child in &def.children {
+                b.add_u32(child.tag)?;
+                b.add_u32(child.min)?;
+                b.add_u32(child.max.unwrap_or(u32::MAX))?;
+            }
+            // Dimension (Only vector.)
+            if def.data_type == Type::Vector {
+                b.add_u32(def.dimension)?;
+            }
+            // Scale (Only decimal.)
+            if def.data_type == Type::Decimal {
+                b.add_u8(def.scale);
+            }
+            // Enumeration members (Only enumeration.)
+            if def.data_type == Type::Enum {
+                b.add_usize(def.members.len())?;
+                for member in &def.members {
+                    b.add_str(member)?;
+                }
+            }
+            // Constraints (bit 0: min, bit 1: max, bit 2: max length, bit 3: not empty, bit 4: pattern)
+            let c = &def.constraints;
+            b.add_u8(u8::from(c.min.is_some()) | u8::from(c.max.is_some()) << 1 | u8::from(c.max_len.is_some()) << 2
+                | u8::from(c.not_empty) << 3 | u8::from(c.get_pattern().is_some()) << 4);
+            for bound in [c.min, c.max].into_iter().flatten() {
+                write_number(b, bound);
+            }
+            if let Some(max_len) = c.max_len {
+                b.add_u32(max_len)?;
+            }
+            if let Some(pattern) = c.get_pattern() {
+                b.add_str(pattern)?;
+            }
+            // Default value (has value, length and binary)
+            b.add_u8(u8::from(def.default_value.is_some()));
+            if let Some(value) = &def.default_value {
+                b.add_usize(value.len())?;
+                for bite in value {
+                    b.add_u8(*bite);
                 }
-                // ETX, Check sum
-                b.end_child_and_add_data()?;
             }
-            b.write(&self.path_manager.get_def_path()?);
+            // Base definition (has base and tag)
+            b.add_u8(u8::from(def.base.is_some()));
+            if let Some(base) = def.base {
+                b.add_u32(base)?;
+            }
+            // Max depth (has max depth and depth)
+            b.add_u8(u8::from(def.max_depth.is_some()));
+            if let Some(max_depth) = def.max_depth {
+                b.add_u32(max_depth)?;
+            }
             Ok(())
         }
 
-        /// Read definition data from file
+        /// get fingerprint of definitions.  
+        /// It hashes the canonical form of the schema fields: tag, name, type, flags, children, dimension, scale,
+        /// members and constraints. Explanations, default values, base definitions and max depths are not hashed,
+        /// and the fingerprint does not depend on the order of definitions and the record format.
+        pub fn def_fingerprint(&self) -> u64 {
+            let mut defs: Vec<&Definition> = self.def_list.iter().collect();
+            defs.sort_by_key(|def| def.tag);
+            let mut vec = vec![FINGERPRINT_VERSION];
+            for def in defs {
+                push_canonical_def(&mut vec, def);
+            }
+            fingerprint(&vec)
+        }
+
+        /// Read definition data from file  
+        /// The definitions which are read are checked by `check_definitions`, and the issues are refused or warned.
         pub fn read_def_from_file(&mut self) -> Result<()> {
+            let mut binary_manager = BinaryManager::new();
+            let header = binary_manager.read_to_end(&self.path_manager.get_def_path()?)?;
+            check_kind(&header, FileKind::Definition)?;
             let backup_def = self.def_list.clone();
             self.def_list = Vec::new();
-            let mut binary_manager = BinaryManager::new();
-            binary_manager.read_to_end(&self.path_manager.get_def_path()?)?;
-            match self.read_def_from_binary(&mut binary_manager) {
+            let result = self.read_def_from_binary(&mut binary_manager)
+                .and_then(|_| self.report_def_issues());
+            match result {
                 Ok(_) => Ok(()),
                 Err(e) => {
                     // If an error occurs, apply the backup data.
                     self.def_list = backup_def;
+                    self.resolved_defs = OnceLock::new();
                     Err(e)
                 }
             }
@@ -171,71 +1400,152 @@ pub mod manager {
         /// Read definition data from binary data.
         /// * `b` - binary data manager.
         pub fn read_def_from_binary(&mut self, b: &mut BinaryManager) -> Result<()> {
-            while b.next_stx_index() {
-                b.create_child(Mode::Read);
+            while b.next_record() {
+                // STX, length
+                b.create_child(Mode::Read)?;
 
-                // STX
-                b.get_child()?.read_u8();
                 // Tag
                 let tag = b.get_child()?.read_u32()?;
 
                 let name = b.get_child()?.read_str()?;
                 // Data type
-                let data_type: Type = Type::u8_to_type(b.get_child()?.read_u8());
-                // Is multiple
-                let is_multiple = b.get_child()?.read_u8() != 0;
+                let data_type: Type = Type::u8_to_type(b.get_child()?.read_u8()?)?;
+                // Flags (bit 0: is multiple, bit 1: is nullable, bit 2: is base, bit 3: is recursive)
+                let flags = b.get_child()?.read_u8()?;
 
                 // make new definition structure.
-                let mut def = Definition::new(tag, name, data_type, is_multiple);
+                let mut def = Definition::new(tag, name, data_type, flags & 0x01 != 0);
+                def.is_nullable = flags & 0x02 != 0;
+                def.is_base = flags & 0x04 != 0;
+                def.is_recursive = flags & 0x08 != 0;
+                self.attach_codec(&mut def);
 
                 // Explanation
                 def.explanation = b.get_child()?.read_str()?;
-                // Children Tags
-                for _ in 0..b.get_child()?.read_u128()? as usize {
-                    def.children.push(b.get_child()?.read_u32()?);
+                // Children tags and cardinality (Cardinality is written since version 5.)
+                for _ in 0..b.get_child()?.read_usize()? {
+                    let mut child = ChildDef::new(b.get_child()?.read_u32()?);
+                    if b.get_version() >= 5 {
+                        child.min = b.get_child()?.read_u32()?;
+                        child.max = Some(b.get_child()?.read_u32()?).filter(|max| *max != u32::MAX);
+                    }
+                    def.children.push(child);
+                }
+                // Dimension (Only vector.)
+                if def.data_type == Type::Vector {
+                    def.dimension = b.get_child()?.read_u32()?;
                 }
-                // ETX
-                b.get_child()?.read_u8();
-                // Check sum and get child data.
+                // Scale (Only decimal.)
+                if def.data_type == Type::Decimal {
+                    def.scale = b.get_child()?.read_u8()?;
+                    if def.scale > MAX_SCALE {
+                        return Err(FractalError::InvalidScale(def.tag));
+                    }
+                }
+                // Enumeration members (Only enumeration.)
+                if def.data_type == Type::Enum {
+                    let mut members = Vec::new();
+                    for _ in 0..b.get_child()?.read_usize()? {
+                        members.push(b.get_child()?.read_str()?);
+                    }
+                    def.set_members(members)?;
+                }
+                // Constraints (Since version 6.)
+                if b.get_version() >= 6 {
+                    let flags = b.get_child()?.read_u8()?;
+                    let c = &mut def.constraints;
+                    if flags & 0x01 != 0 {
+                        c.min = Some(read_number(b)?);
+                    }
+                    if flags & 0x02 != 0 {
+                        c.max = Some(read_number(b)?);
+                    }
+                    if flags & 0x04 != 0 {
+                        c.max_len = Some(b.get_child()?.read_u32()?);
+                    }
+                    c.not_empty = flags & 0x08 != 0;
+                    if flags & 0x10 != 0 {
+                        c.set_pattern(Some(b.get_child()?.read_str()?))?;
+                    }
+                }
+                // Default value (Since version 7.)
+                if b.get_version() >= 7 && b.get_child()?.read_u8()? != 0 {
+                    let len = b.get_child()?.read_usize()?;
+                    def.default_value = Some(b.get_child()?.read_u8_vec(len)?);
+                }
+                // Base definition (Since version 8.)
+                if b.get_version() >= 8 && b.get_child()?.read_u8()? != 0 {
+                    def.base = Some(b.get_child()?.read_u32()?);
+                }
+                // Max depth (Since version 9.)
+                if b.get_version() >= 9 && b.get_child()?.read_u8()? != 0 {
+                    def.max_depth = Some(b.get_child()?.read_u32()?);
+                }
+                // ETX, Check sum
                 b.read_child()?;
 
                 self.def_list.push(def);
             }
+            self.resolved_defs = OnceLock::new();
 
             Ok(())
         }
 
-        /// write data in file.
+        /// write data in file.  
+        /// Return error if any reference is dangling.
         pub fn write_data(&self) -> Result<()> {
+            self.check_references()?;
             let mut binary_manager = BinaryManager::new();
+            binary_manager.set_checksum(self.checksum);
             for child in self.parent_data.get_children() {
                 self.write_child_data(child, &mut binary_manager)?
             }
+            let header = FileHeader::new(FileKind::Data, Some(self.def_fingerprint()));
+            binary_manager.write(&self.path_manager.get_data_path()?, &header)?;
+            self.remove_unused_blobs()
+        }
+
+        /// remove blob files which no data in this data name refers to.
+        fn remove_unused_blobs(&self) -> Result<()> {
+            let mut used = HashSet::new();
+            for def in self.def_list.iter().filter(|def| def.data_type == Type::Bytes) {
+                for data in self.parent_data.find_by_tag(def.tag) {
+                    let value = data.get_bite_value();
+                    match data.get_blob() {
+                        Some(blob) => { used.insert(self.path_manager.get_blob_path(blob.hash)?); },
+                        None if value.len() > self.blob_threshold => {
+                            used.insert(self.path_manager.get_blob_path(content_hash(value))?);
+                        },
+                        None => (),
+                    }
+                }
+            }
+            for entry in fs::read_dir(self.path_manager.get_blob_dir()?)? {
+                let path = entry?.path();
+                if path.extension().is_some_and(|e| e == "blob") && !used.contains(&path) {
+                    fs::remove_file(&path)?;
+                }
+            }
             Ok(())
         }
 
-        /// write child data in file.
+        /// write child data in binary data.
         /// * `data` - written data.
         /// * `b` - binary data manager.
         pub fn write_child_data(&self, data: &Data, b: &mut BinaryManager) -> Result<()> {
             // able to write only defined tag.
-            b.create_child(Mode::Write);
-            // STX
-            b.get_child()?.add_start_data();
+            check_codec(data.get_def())?;
+            b.create_child(Mode::Write)?;
             // Tag
             b.get_child()?.add_u32(data.get_def().tag)?;
 
-            // Value length (Only values ​​with undefined length.)
-            match data.get_def().data_type {
-                Type::String => {
-                    b.get_child()?.add_usize(data.get_bite_value().len())?;
-                }
-                _ => ()
-            };
+            // Has value (Only nullable.)
+            if data.get_def().is_nullable {
+                b.get_child()?.add_bool(!data.is_null());
+            }
 
-            // Write value in file
-            for bite in data.get_bite_value() {
-                b.get_child()?.add_u8(*bite);
+            if !data.is_null() {
+                self.write_value(data, b.get_child()?)?;
             }
 
             // Children
@@ -243,17 +1553,123 @@ pub mod manager {
                 self.write_child_data(child, b.get_child()?)?;
             }
 
-            // ETX, Check sum
+            // STX, length, ETX, Check sum
             b.end_child_and_add_data()?;
-            b.write(&self.path_manager.get_data_path()?);
             Ok(())
         }
 
-        /// read data from file.
+        /// write value of data in binary data.
+        /// * `data` - written data.
+        /// * `b` - binary data manager.
+        fn write_value(&self, data: &Data, b: &mut BinaryManager) -> Result<()> {
+            if data.get_def().data_type == Type::Bytes {
+                return self.write_bytes_value(data, b);
+            }
+            // Value length (Only values ​​with undefined length.)
+            if data.get_def().value_size().is_none() {
+                b.add_usize(data.get_bite_value().len())?;
+            }
+
+            // Write value in file
+            for bite in data.get_bite_value() {
+                b.add_u8(*bite);
+            }
+            Ok(())
+        }
+
+        /// write binary payload in binary data.  
+        /// The payload over the blob threshold is written in blob file, and the record has only content hash and length.
+        /// * `data` - written data.
+        /// * `b` - binary data manager.
+        fn write_bytes_value(&self, data: &Data, b: &mut BinaryManager) -> Result<()> {
+            let (hash, len) = match data.get_blob() {
+                Some(blob) => {
+                    let path = self.path_manager.get_blob_path(blob.hash)?;
+                    if !is_written_blob(&path, blob.hash, blob.len) {
+                        // The broken blob cannot be repaired from itself.
+                        if blob.path == path {
+                            return Err(FractalError::BlobMismatch(blob.hash));
+                        }
+                        fs::copy(&blob.path, &path)?;
+                    }
+                    (blob.hash, blob.len)
+                },
+                None if data.get_bite_value().len() > self.blob_threshold => {
+                    let value = data.get_bite_value();
+                    let hash = content_hash(value);
+                    // Blob files are named by content hash, so the same content is written once.
+                    let path = self.path_manager.get_blob_path(hash)?;
+                    if !is_written_blob(&path, hash, value.len() as u64) {
+                        fs::write(&path, value)?;
+                    }
+                    (hash, value.len() as u64)
+                },
+                None => {
+                    // Inline
+                    b.add_bool(false);
+                    b.add_usize(data.get_bite_value().len())?;
+                    for bite in data.get_bite_value() {
+                        b.add_u8(*bite);
+                    }
+                    return Ok(());
+                },
+            };
+            // Blob
+            b.add_bool(true);
+            b.add_u64(hash);
+            b.add_u64(len);
+            Ok(())
+        }
+
+        /// read data from file.  
+        /// The data which is read replaces the current data.  
+        /// If the data file is written against other definitions, it is refused or warned.  
+        /// Return error if any reference is dangling.
         pub fn read_data(&mut self) -> Result<()> {
+            let backup_data = self.parent_data.clone();
+            let result = self.read_data_file()
+                .and_then(|_| self.check_constraints())
+                .and_then(|_| self.check_depths())
+                .and_then(|_| self.check_references())
+                .and_then(|_| self.build_vector_indexes())
+                .and_then(|_| self.build_spatial_indexes());
+            match result {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    // If an error occurs, apply the backup data.
+                    self.parent_data = backup_data;
+                    Err(e)
+                }
+            }
+        }
+
+        /// read data from file without checking references.
+        fn read_data_file(&mut self) -> Result<()> {
             let mut binary_manager = BinaryManager::new();
-            binary_manager.read_to_end(&self.path_manager.get_data_path()?)?;
-            self.read_child_data(&[], &mut binary_manager)?;
+            let header = binary_manager.read_to_end(&self.path_manager.get_data_path()?)?;
+            check_kind(&header, FileKind::Data)?;
+            if let Some(actual) = header.def_fingerprint {
+                let expected = self.def_fingerprint();
+                if expected != actual {
+                    match self.definition_check {
+                        DefinitionCheck::Refuse =>
+                            return Err(FractalError::DefinitionMismatch { expected, actual }),
+                        DefinitionCheck::Warn =>
+                            self.warnings.push(FractalError::DefinitionMismatch { expected, actual }),
+                    }
+                }
+            }
+            self.data_header = Some(header);
+            self.parent_data.clear_children();
+            self.read_all_child_data(&mut binary_manager)
+        }
+
+        /// read all of top level data from binary data.
+        /// * `b` - Binary manager.
+        fn read_all_child_data(&mut self, b: &mut BinaryManager) -> Result<()> {
+            while self.read_child_data(&[], b)? {
+                continue;
+            }
             Ok(())
         }
 
@@ -261,48 +1677,55 @@ pub mod manager {
         /// * `index` - Index from parent to here.
         /// * `b` - Binary manager.
         pub fn read_child_data(&mut self, index: &[usize], b: &mut BinaryManager) -> Result<bool> {
-            while b.next_stx_index() {
-                b.create_child(Mode::Read);
-                // STX
-                b.get_child()?.read_u8();
+            if b.next_record() {
+                // STX, length
+                b.create_child(Mode::Read)?;
                 // Tag
                 let tag = b.get_child()?.read_u32()?;
+                if index.len() >= MAX_DATA_DEPTH {
+                    return Err(FractalError::DepthExceeded { tag, depth: index.len() + 1 });
+                }
 
                 let def = self.get_def(&tag)?;
+                check_codec(def)?;
+
+                // Has value (Only nullable.)
+                let is_null = def.is_nullable && b.get_child()?.read_u8()? == 0;
 
                 // data value
-                let value = match def.get_type() {
-                    Type::Int => b.get_child()?.read_u8_vec(4),
-                    Type::Float => b.get_child()?.read_u8_vec(4),
-                    Type::String => {
-                        let len_bite = b.get_child()?.read_u128()?;
-                        let len = len_bite as usize;
-                        let vec: Vec<u8> = Vec::new();
-                        let mut vec = u128_to_slice(len_bite, &mut vec.clone(), 120)?;
-                        let vec2 = &mut b.get_child()?.read_u8_vec(len).clone();
-                        vec.append(vec2);
-                        vec
-                    },
+                let data = if is_null {
+                    Data::null(def.clone())?
+                }
+                else if def.get_type() == &Type::Bytes && b.get_child()?.read_u8()? != 0 {
+                    // Blob (content is read lazily)
+                    let hash = b.get_child()?.read_u64()?;
+                    let len = b.get_child()?.read_u64()?;
+                    Data::read_blob(def.clone(), Blob::new(hash, len, self.path_manager.get_blob_path(hash)?))
+                }
+                else {
+                    let len = match def.value_size() {
+                        Some(size) => size,
+                        // Value length (Only values with undefined length.)
+                        None => b.get_child()?.read_usize()?,
+                    };
+                    Data::read_binary(def.clone(), b.get_child()?.read_u8_vec(len)?)
                 };
 
-                let data = Data::read_binary(def.clone(), value);
-
                 // add data in parent.
-                let parent = self.parent_data.get_child_mut_by_index(index)
-                    .expect("Fail to read tag from file.");
-                parent.add_child(&mut [], data);
+                let parent = match self.parent_data.get_child_mut_by_index(index) {
+                    Some(parent) => parent,
+                    None => return Err(FractalError::DataNotFound),
+                };
+                parent.add_child(&[], data)?;
 
                 // Children
                 let mut index_vec = index.to_vec();
                 index_vec.push(parent.get_children().len() - 1);
-                while self.read_child_data(&mut index_vec, b.get_child()?)? {
+                while self.read_child_data(&index_vec, b.get_child()?)? {
                     continue;
                 }
 
-                // ETX
-                b.get_child()?.read_u8();
-
-                // Check sum and read child data.
+                // ETX, Check sum
                 b.read_child()?;
                 return Ok(true);
             }
@@ -310,38 +1733,1275 @@ pub mod manager {
         }
     }
 
+    /// Return true if the blob file is already written and its content is not broken.
+    /// * `path` - blob file path.
+    /// * `hash` - content hash.
+    /// * `len` - byte length of content.
+    fn is_written_blob(path: &Path, hash: u64, len: u64) -> bool {
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.len() == len => Blob::new(hash, len, path.to_path_buf()).read_all().is_ok(),
+            _ => false,
+        }
+    }
+
+    /// Check that the codec of user defined type is registered.
+    /// * `def` - definition.
+    fn check_codec(def: &Definition) -> Result<()> {
+        match def.data_type {
+            Type::Custom(code) if def.codec.get().is_none() => Err(FractalError::UnregisteredCodec(code)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check kind of file.  
+    /// The file which does not record kind is accepted.
+    /// * `header` - file header.
+    /// * `expected` - expected kind.
+    fn check_kind(header: &FileHeader, expected: FileKind) -> Result<()> {
+        if header.kind == expected || header.kind == FileKind::Unknown {
+            Ok(())
+        }
+        else {
+            Err(FractalError::FileKindMismatch { expected, actual: header.kind })
+        }
+    }
+
+    /// Write numeric constraint bound.  
+    /// Floating point bound is tag 0 and bits of f64, integer bound is tag 1 and high and low u64 of i128.
+    /// * `b` - binary manager.
+    /// * `number` - bound.
+    fn write_number(b: &mut BinaryManager, number: Number) {
+        match number {
+            Number::Float(value) => {
+                b.add_u8(0);
+                b.add_u64(value.to_bits());
+            },
+            Number::Int(value) => {
+                b.add_u8(1);
+                b.add_u64((value >> 64) as u64);
+                b.add_u64(value as u64);
+            },
+        }
+    }
+
+    /// Read numeric constraint bound.  
+    /// Bound before version 10 is bits of f64.
+    /// * `b` - binary manager.
+    fn read_number(b: &mut BinaryManager) -> Result<Number> {
+        if b.get_version() < 10 {
+            return Ok(Number::Float(f64::from_bits(b.get_child()?.read_u64()?)));
+        }
+        match b.get_child()?.read_u8()? {
+            0 => Ok(Number::Float(f64::from_bits(b.get_child()?.read_u64()?))),
+            1 => {
+                let high = b.get_child()?.read_u64()?;
+                let low = b.get_child()?.read_u64()?;
+                Ok(Number::Int(i128::from(high as i64) << 64 | i128::from(low)))
+            },
+            tag => Err(FractalError::UnknownNumberTag(tag)),
+        }
+    }
+
+    /// push the canonical form of the schema fields of definition.
+    /// Integers are little endian, and strings and lists are prefixed by their length (u32).
+    /// * `vec` - canonical form of definitions.
+    /// * `def` - definition.
+    fn push_canonical_def(vec: &mut Vec<u8>, def: &Definition) {
+        vec.extend_from_slice(&def.tag.to_le_bytes());
+        push_str(vec, &def.name);
+        vec.push(def.get_type_num());
+        vec.push(u8::from(def.is_multiple) | u8::from(def.is_nullable) << 1 | u8::from(def.is_base) << 2
+            | u8::from(def.is_recursive) << 3);
+        vec.extend_from_slice(&(def.children.len() as u32).to_le_bytes());
+        for child in &def.children {
+            vec.extend_from_slice(&child.tag.to_le_bytes());
+            vec.extend_from_slice(&child.min.to_le_bytes());
+            vec.extend_from_slice(&child.max.unwrap_or(u32::MAX).to_le_bytes());
+        }
+        vec.extend_from_slice(&def.dimension.to_le_bytes());
+        vec.push(def.scale);
+        vec.extend_from_slice(&(def.members.len() as u32).to_le_bytes());
+        for member in &def.members {
+            push_str(vec, member);
+        }
+        let c = &def.constraints;
+        // Floating point bound keeps the encoding of fingerprint version 1.
+        for bound in [c.min, c.max] {
+            match bound {
+                Some(Number::Float(bound)) => {
+                    vec.push(1);
+                    vec.extend_from_slice(&bound.to_bits().to_le_bytes());
+                },
+                Some(Number::Int(bound)) => {
+                    vec.push(2);
+                    vec.extend_from_slice(&bound.to_le_bytes());
+                },
+                None => vec.push(0),
+            }
+        }
+        match c.max_len {
+            Some(max_len) => {
+                vec.push(1);
+                vec.extend_from_slice(&max_len.to_le_bytes());
+            },
+            None => vec.push(0),
+        }
+        vec.push(u8::from(c.not_empty));
+        match c.get_pattern() {
+            Some(pattern) => {
+                vec.push(1);
+                push_str(vec, pattern);
+            },
+            None => vec.push(0),
+        }
+    }
+
+    /// push length (u32) and string.
+    fn push_str(vec: &mut Vec<u8>, text: &str) {
+        vec.extend_from_slice(&(text.len() as u32).to_le_bytes());
+        vec.extend_from_slice(text.as_bytes());
+    }
+
     #[cfg(test)]
     extern crate speculate;
 
     #[cfg(test)]
     use speculate::speculate;
 
-    // Test Command
-    // cargo test -- --test-threads=1 > test.txt
     #[cfg(test)]
     speculate! {
+        before {
+            // Each test has its own folder in the temp directory, so tests can run in parallel.
+            let temp_dir = |name: &str| std::env::temp_dir().join(format!("our_fractal_core_{}", name));
+            let new_manager = |path: &Path, data_name: &str| Manager::new(path, "test".to_string(), data_name.to_string());
+        }
+
         describe "definition" {
             it "tag list" {
-                let path = "./test";
-                let table_name = format!("test");
-                let data_name = format!("test");
-                let path = &std::path::PathBuf::from(path);
-                let mut manager = Manager::new(path, table_name, data_name);
-                manager.add_def(0xabcd_abcd, format!("Int test"), Type::Int, false).unwrap();
-                manager.add_def(0x1234_5678, format!("child test"), Type::Int, false).unwrap();
+                let mut manager = new_manager(&temp_dir("tag_list"), "test");
+                manager.add_def(0xabcd_abcd, "Int test".to_string(), Type::Int, false).unwrap();
+                manager.add_def(0x1234_5678, "child test".to_string(), Type::Int, false).unwrap();
                 assert_eq!(manager.get_def_tag_list(), vec![0x0000_0000, 0xabcd_abcd, 0x1234_5678]);
             }
             it "add definition child" {
-                let path = "./test";
-                let table_name = format!("test");
-                let data_name = format!("test");
-                let path = &std::path::PathBuf::from(path);
-                let mut manager = Manager::new(path, table_name, data_name);
-                manager.add_def(0xabcd_abcd, format!("Int test"), Type::Int, false).unwrap();
-                manager.add_def(0x1234_5678, format!("child test"), Type::Int, false).unwrap();
-                manager.add_def_child(&0xabcd_abcd, 0x1234_5678);
-                assert_eq!(manager.get_def(&0xabcd_abcd).unwrap().children, vec![0x1234_5678]);
+                let mut manager = new_manager(&temp_dir("add_definition_child"), "test");
+                manager.add_def(0xabcd_abcd, "Int test".to_string(), Type::Int, false).unwrap();
+                manager.add_def(0x1234_5678, "child test".to_string(), Type::Int, false).unwrap();
+                manager.add_def_child(&0xabcd_abcd, 0x1234_5678).unwrap();
+                // The child which is already added is not added again.
+                manager.add_def_child(&0xabcd_abcd, 0x1234_5678).unwrap();
+                assert_eq!(manager.get_def(&0xabcd_abcd).unwrap().get_child_tags(), vec![0x1234_5678]);
+                assert!(matches!(manager.add_def_child(&0xabcd_abcd, 0x0009_0009),
+                    Err(FractalError::UnknownTag(0x0009_0009))));
+                assert!(matches!(manager.add_def_child(&0x0009_0009, 0x1234_5678),
+                    Err(FractalError::UnknownTag(0x0009_0009))));
+            }
+            it "duplicate tag" {
+                let mut manager = new_manager(&temp_dir("duplicate_tag"), "test");
+                manager.add_def(0xabcd_abcd, "Int test".to_string(), Type::Int, false).unwrap();
+                match manager.add_def(0xabcd_abcd, "Int test".to_string(), Type::Int, false) {
+                    Err(FractalError::DuplicateTag(tag)) => assert_eq!(tag, 0xabcd_abcd),
+                    _ => panic!("duplicate tag is not detected"),
+                }
+            }
+            it "unknown tag" {
+                let manager = new_manager(&temp_dir("unknown_tag"), "test");
+                match manager.get_def(&0xabcd_abcd) {
+                    Err(FractalError::UnknownTag(tag)) => assert_eq!(tag, 0xabcd_abcd),
+                    _ => panic!("unknown tag is not detected"),
+                }
+            }
+            it "child check" {
+                let mut manager = new_manager(&temp_dir("child_check"), "test");
+                manager.add_def(0xabcd_abcd, "Int test".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x1234_5678, "child test".to_string(), Type::Int, false).unwrap();
+                manager.add_def_child(&0xabcd_abcd, 0x1234_5678).unwrap();
+                let parent = manager.get_def(&0xabcd_abcd).unwrap().clone();
+                let child = manager.get_def(&0x1234_5678).unwrap().clone();
+                manager.add_child(Data::new(parent.clone(), 1).unwrap(), &[]).unwrap();
+                manager.add_child(Data::new(child.clone(), 2).unwrap(), &[(0xabcd_abcd, None)]).unwrap();
+                match manager.add_child(Data::new(child.clone(), 3).unwrap(), &[(0xabcd_abcd, None)]) {
+                    Err(FractalError::DuplicateChild { parent, child }) => assert_eq!((parent, child), (0xabcd_abcd, 0x1234_5678)),
+                    _ => panic!("duplicate child is not detected"),
+                }
+                // Descendants of the added data are also checked.
+                let mut data = Data::new(child.clone(), 4).unwrap();
+                data.add_child(&[], Data::new(parent.clone(), 5).unwrap()).unwrap();
+                match manager.add_child(data.clone(), &[]) {
+                    Err(FractalError::ChildNotAllowed { parent, child }) => assert_eq!((parent, child), (0x1234_5678, 0xabcd_abcd)),
+                    _ => panic!("child not allowed is not detected"),
+                }
+                manager.set_child_check(ChildCheck::Allow);
+                manager.add_child(data, &[]).unwrap();
+                manager.add_child(Data::new(child, 3).unwrap(), &[(0xabcd_abcd, None)]).unwrap();
+            }
+            it "instantiate" {
+                let path = &temp_dir("instantiate");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Order".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x0001_0002, "Status".to_string(), Type::String, false).unwrap();
+                manager.add_def(0x0001_0003, "Item".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x0001_0004, "Note".to_string(), Type::String, false).unwrap();
+                manager.add_def(0x0001_0005, "Quantity".to_string(), Type::U32, false).unwrap();
+                manager.set_child_range(&0x0001_0001, 0x0001_0002, 1, Some(1)).unwrap();
+                manager.set_child_range(&0x0001_0001, 0x0001_0003, 2, None).unwrap();
+                manager.add_def_child(&0x0001_0001, 0x0001_0004).unwrap();
+                manager.set_child_range(&0x0001_0003, 0x0001_0005, 1, Some(1)).unwrap();
+                manager.get_def_mut(&0x0001_0001).unwrap().set_default(0).unwrap();
+                manager.get_def_mut(&0x0001_0002).unwrap().set_default("open").unwrap();
+                manager.get_def_mut(&0x0001_0003).unwrap().set_default(-1).unwrap();
+                manager.get_def_mut(&0x0001_0005).unwrap().set_nullable(true);
+                assert!(matches!(manager.get_def_mut(&0x0001_0005).unwrap().set_default(1),
+                    Err(FractalError::TypeMismatch { .. })));
+
+                let order = manager.instantiate(&0x0001_0001).unwrap();
+                assert_eq!(order.get::<i32>().unwrap(), 0);
+                let tags: Vec<u32> = order.get_children().iter().map(|e| e.get_def().tag).collect();
+                assert_eq!(tags, vec![0x0001_0002, 0x0001_0003, 0x0001_0003]);
+                assert_eq!(order.get_child(&[(0x0001_0002, None)]).unwrap().get::<String>().unwrap(), format!("open"));
+                assert!(order.get_child(&[(0x0001_0003, None), (0x0001_0005, None)]).unwrap().is_null());
+                manager.add_child(order, &[]).unwrap();
+                assert!(manager.validate().is_empty());
+                let order = manager.instantiate_with(&0x0001_0001, 7).unwrap();
+                assert_eq!((order.get::<i32>().unwrap(), order.get_children().len()), (7, 3));
+
+                // Default values are persisted.
+                manager.write_def().unwrap();
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                assert_eq!(read.get_def(&0x0001_0002).unwrap().get_default().unwrap(), Some(Value::from("open")));
+                assert_eq!(read.instantiate(&0x0001_0001).unwrap(), manager.instantiate(&0x0001_0001).unwrap());
+
+                manager.get_def_mut(&0x0001_0002).unwrap().clear_default();
+                assert!(matches!(manager.instantiate(&0x0001_0001), Err(FractalError::NoDefault(0x0001_0002))));
+                // A recursive definition can require its ancestor, but it cannot be made.
+                manager.get_def_mut(&0x0001_0002).unwrap().set_recursive(true, None);
+                manager.set_child_range(&0x0001_0002, 0x0001_0001, 1, None).unwrap();
+                manager.get_def_mut(&0x0001_0002).unwrap().set_default("open").unwrap();
+                assert!(matches!(manager.instantiate(&0x0001_0001), Err(FractalError::CyclicDefinition(0x0001_0001))));
+            }
+
+            it "cardinality" {
+                let path = &temp_dir("cardinality");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Order".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x0001_0002, "Customer".to_string(), Type::String, false).unwrap();
+                manager.add_def(0x0001_0003, "Item".to_string(), Type::String, true).unwrap();
+                manager.set_child_range(&0x0001_0001, 0x0001_0002, 1, Some(1)).unwrap();
+                manager.set_child_range(&0x0001_0001, 0x0001_0003, 2, Some(3)).unwrap();
+                match manager.set_child_range(&0x0001_0001, 0x0001_0003, 2, Some(1)) {
+                    Err(FractalError::InvalidCardinality { child, .. }) => assert_eq!(child, 0x0001_0003),
+                    _ => panic!("invalid cardinality is not detected"),
+                }
+                let order = manager.get_def(&0x0001_0001).unwrap().clone();
+                let item = manager.get_def(&0x0001_0003).unwrap().clone();
+                for (i, items) in [1, 3].into_iter().enumerate() {
+                    let mut data = Data::new(order.clone(), i as i32).unwrap();
+                    for j in 0..items {
+                        data.add_child(&[], Data::new(item.clone(), format!("item{}-{}", i, j)).unwrap()).unwrap();
+                    }
+                    manager.add_child(data, &[]).unwrap();
+                }
+                let root = [(0x0001_0001, Some(Data::new(item.clone(), "item1-0").unwrap()))];
+                match manager.add_child(Data::new(item, "ink").unwrap(), &root) {
+                    Err(FractalError::Cardinality { child, count, .. }) => assert_eq!((child, count), (0x0001_0003, 4)),
+                    _ => panic!("max number is not checked"),
+                }
+
+                // Both orders have no customer, and the first order has only one item.
+                let violations = manager.validate();
+                let found: Vec<_> = violations.iter().map(|e| match e.error {
+                    FractalError::Cardinality { child, count, .. } => (e.index.clone(), child, count),
+                    _ => panic!("unexpected violation"),
+                }).collect();
+                assert_eq!(found, vec![(vec![0], 0x0001_0002, 0), (vec![0], 0x0001_0003, 1), (vec![1], 0x0001_0002, 0)]);
+                assert_eq!(manager.get_data(violations[2].root.as_ref().unwrap()).unwrap().get::<i32>().unwrap(), 1);
+
+                // Cardinality is persisted.
+                manager.write_def().unwrap();
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                assert_eq!(read.get_def(&0x0001_0001).unwrap().get_child(0x0001_0003),
+                    Some(&ChildDef::with_range(0x0001_0003, 2, Some(3))));
+            }
+
+            it "inheritance" {
+                let path = &temp_dir("inheritance");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Party".to_string(), Type::String, true).unwrap();
+                manager.add_def(0x0001_0002, "Customer".to_string(), Type::String, true).unwrap();
+                manager.add_def(0x0001_0003, "Name".to_string(), Type::String, false).unwrap();
+                manager.add_def(0x0001_0004, "Rank".to_string(), Type::Int, false).unwrap();
+                manager.add_def(0x0001_0005, "Company".to_string(), Type::Int, true).unwrap();
+                manager.set_child_range(&0x0001_0001, 0x0001_0003, 1, Some(1)).unwrap();
+                let party = manager.get_def_mut(&0x0001_0001).unwrap();
+                party.set_explanation("person or organization".to_string());
+                party.constraints.max_len = Some(8);
+                party.constraints.not_empty = true;
+                match manager.set_base(&0x0001_0002, Some(0x0001_0001)) {
+                    Err(FractalError::NotBase(tag)) => assert_eq!(tag, 0x0001_0001),
+                    _ => panic!("not base is not detected"),
+                }
+                manager.get_def_mut(&0x0001_0001).unwrap().set_is_base(true);
+                assert!(matches!(manager.set_base(&0x0001_0005, Some(0x0001_0001)), Err(FractalError::TypeMismatch { .. })));
+                manager.set_base(&0x0001_0002, Some(0x0001_0001)).unwrap();
+                manager.set_child_range(&0x0001_0002, 0x0001_0004, 0, Some(1)).unwrap();
+                manager.get_def_mut(&0x0001_0002).unwrap().constraints.max_len = Some(4);
+
+                let customer = manager.get_def(&0x0001_0002).unwrap().clone();
+                assert_eq!(customer.get_child_tags(), vec![0x0001_0003, 0x0001_0004]);
+                assert_eq!(customer.get_explanation(), &"person or organization".to_string());
+                assert_eq!((customer.constraints.max_len, customer.constraints.not_empty), (Some(4), true));
+                assert_eq!(manager.get_declared_def(&0x0001_0002).unwrap().get_child_tags(), vec![0x0001_0004]);
+                assert!(matches!(Data::new(customer.clone(), "Hanako"), Err(FractalError::TooLong { .. })));
+                let mut data = Data::new(customer, "Taro").unwrap();
+                data.add_child(&[], Data::new(manager.get_def(&0x0001_0003).unwrap().clone(), "Taro").unwrap()).unwrap();
+                manager.add_child(data, &[]).unwrap();
+                assert!(manager.validate().is_empty());
+
+                manager.get_def_mut(&0x0001_0002).unwrap().set_is_base(true);
+                match manager.set_base(&0x0001_0001, Some(0x0001_0002)) {
+                    Err(FractalError::CyclicDefinition(tag)) => assert_eq!(tag, 0x0001_0001),
+                    _ => panic!("cyclic definition is not detected"),
+                }
+
+                // Inheritance is persisted.
+                manager.write_def().unwrap();
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                assert_eq!(read.get_declared_def(&0x0001_0002).unwrap().base, Some(0x0001_0001));
+                assert!(read.get_def(&0x0001_0001).unwrap().is_base());
+                assert_eq!(read.get_def(&0x0001_0002).unwrap().get_child_tags(), vec![0x0001_0003, 0x0001_0004]);
+            }
+
+            it "check definitions" {
+                let path = &temp_dir("check_definitions");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Order".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x0001_0002, "Item".to_string(), Type::String, true).unwrap();
+                assert!(matches!(manager.add_def(0x0001_0003, "Item".to_string(), Type::String, true),
+                    Err(FractalError::DuplicateName(_))));
+                manager.add_def(0x0001_0003, "Other".to_string(), Type::String, true).unwrap();
+                manager.add_def(0x0001_0004, "Node".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x0001_0005, "Edge".to_string(), Type::Int, true).unwrap();
+                manager.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                // Names which are changed directly are not checked.
+                manager.get_def_mut(&0x0001_0003).unwrap().name = "Item".to_string();
+                assert!(matches!(manager.check_definitions().as_slice(), [FractalError::DuplicateName(_)]));
+                // Children which are pushed directly are not checked.
+                manager.get_def_mut(&0x0001_0001).unwrap().children.push(ChildDef::new(0x0009_0009));
+                manager.get_def_mut(&0x0001_0004).unwrap().children.push(ChildDef::new(0x0001_0005));
+                manager.get_def_mut(&0x0001_0005).unwrap().children.push(ChildDef::new(0x0001_0004));
+                let issues = manager.check_definitions();
+                assert_eq!(issues.len(), 4);
+                assert!(matches!(issues[0], FractalError::DanglingChild { parent: 0x0001_0001, child: 0x0009_0009 }));
+                // The definitions in a cycle are not reported as unreachable.
+                assert!(matches!(issues[1], FractalError::CyclicDefinition(0x0001_0004)));
+                assert!(matches!(issues[2], FractalError::CyclicDefinition(0x0001_0005)));
+                assert!(matches!(&issues[3], FractalError::DuplicateName(name) if name == "Item"));
+
+                // The cycle which has a recursive definition is intended, but it is unreachable.
+                manager.get_def_mut(&0x0001_0004).unwrap().set_recursive(true, None);
+                let issues = manager.check_definitions();
+                assert_eq!(issues.len(), 4);
+                let mut unreachable: Vec<u32> = issues[1..3].iter().map(|e| match e {
+                    FractalError::UnreachableDefinition(tag) => *tag,
+                    _ => panic!("unreachable definition is not detected"),
+                }).collect();
+                unreachable.sort();
+                assert_eq!(unreachable, vec![0x0001_0004, 0x0001_0005]);
+
+                // Definitions are checked after read.
+                manager.write_def().unwrap();
+                let mut read = new_manager(path, "test");
+                match read.read_def_from_file() {
+                    Err(FractalError::DanglingChild { child, .. }) => assert_eq!(child, 0x0009_0009),
+                    _ => panic!("dangling child is not detected"),
+                }
+                assert_eq!(read.get_def_tag_list(), vec![0x0000_0000]);
+                read.set_definition_check(DefinitionCheck::Warn);
+                read.read_def_from_file().unwrap();
+                assert_eq!(read.get_def_tag_list(), manager.get_def_tag_list());
+                let warnings = read.take_warnings();
+                assert_eq!(warnings.len(), 4);
+                assert!(matches!(warnings[0], FractalError::DanglingChild { child: 0x0009_0009, .. }));
+                assert!(read.take_warnings().is_empty());
+
+                // Top level definitions are the children of the parent definition if it has children.
+                manager.add_def_child(&0x0000_0000, 0x0001_0001).unwrap();
+                manager.add_def_child(&0x0000_0000, 0x0001_0004).unwrap();
+                let issues = manager.check_definitions();
+                assert_eq!(issues.len(), 3);
+                assert!(matches!(issues[1], FractalError::UnreachableDefinition(0x0001_0003)));
+                let other = Data::new(manager.get_def(&0x0001_0003).unwrap().clone(), "a").unwrap();
+                assert!(matches!(manager.add_child(other, &[]),
+                    Err(FractalError::ChildNotAllowed { parent: 0x0000_0000, child: 0x0001_0003 })));
+            }
+
+            it "recursion" {
+                let path = &temp_dir("recursion");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Folder".to_string(), Type::String, true).unwrap();
+                manager.add_def(0x0001_0002, "File".to_string(), Type::String, true).unwrap();
+                manager.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                match manager.set_child_range(&0x0001_0002, 0x0001_0001, 0, None) {
+                    Err(FractalError::CyclicDefinition(tag)) => assert_eq!(tag, 0x0001_0002),
+                    _ => panic!("cyclic definition is not detected"),
+                }
+                assert!(matches!(manager.add_def_child(&0x0001_0001, 0x0001_0001),
+                    Err(FractalError::CyclicDefinition(0x0001_0001))));
+                manager.get_def_mut(&0x0001_0001).unwrap().set_recursive(true, Some(3));
+                manager.add_def_child(&0x0001_0001, 0x0001_0001).unwrap();
+
+                let folder = manager.get_def(&0x0001_0001).unwrap().clone();
+                let file = manager.get_def(&0x0001_0002).unwrap().clone();
+                let mut data = Data::new(folder.clone(), "a").unwrap();
+                let mut root = vec![(0x0001_0001, None)];
+                for name in ["b", "c"] {
+                    data.add_child(&root[1..], Data::new(folder.clone(), name).unwrap()).unwrap();
+                    root.push((0x0001_0001, None));
+                }
+                manager.add_child(data.clone(), &[]).unwrap();
+                manager.add_child(Data::new(file, "readme").unwrap(), &root).unwrap();
+                match manager.add_child(Data::new(folder.clone(), "d").unwrap(), &root) {
+                    Err(FractalError::DepthExceeded { tag, depth }) => assert_eq!((tag, depth), (0x0001_0001, 4)),
+                    _ => panic!("max depth is not checked"),
+                }
+                let mut deep = Data::new(folder.clone(), "z").unwrap();
+                deep.add_child(&[], data).unwrap();
+                assert!(matches!(manager.add_child(deep.clone(), &[]), Err(FractalError::DepthExceeded { .. })));
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                // Recursion and max depth are persisted.
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                let read_folder = read.get_def(&0x0001_0001).unwrap();
+                assert_eq!((read_folder.is_recursive, read_folder.max_depth), (true, Some(3)));
+                read.read_data().unwrap();
+                assert_eq!(read.get_data(&root).unwrap().get_children().len(), 1);
+
+                // The file which is written with larger max depth is refused.
+                manager.get_def_mut(&0x0001_0001).unwrap().max_depth = Some(4);
+                manager.add_child(deep, &[]).unwrap();
+                manager.write_data().unwrap();
+                read.set_definition_check(DefinitionCheck::Warn);
+                assert!(matches!(read.read_data(), Err(FractalError::DepthExceeded { depth: 4, .. })));
+                assert_eq!(read.get_data(&[]).unwrap().get_children().len(), 1);
+
+                // Any data cannot be nested deeper than MAX_DATA_DEPTH.
+                manager.get_def_mut(&0x0001_0001).unwrap().max_depth = None;
+                let mut data = Data::new(folder.clone(), "0").unwrap();
+                for i in 1..=MAX_DATA_DEPTH {
+                    let mut parent = Data::new(folder.clone(), i.to_string()).unwrap();
+                    parent.add_child(&[], data).unwrap();
+                    data = parent;
+                }
+                match manager.add_child(data, &[]) {
+                    Err(FractalError::DepthExceeded { depth, .. }) => assert_eq!(depth, MAX_DATA_DEPTH + 1),
+                    _ => panic!("max data depth is not checked"),
+                }
+            }
+        }
+
+        describe "file" {
+            it "stx and etx in payload" {
+                let path = &temp_dir("stx_etx");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0203_0203, "Int test".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x0302_0302, "child test".to_string(), Type::Int, false).unwrap();
+                manager.add_def_child(&0x0203_0203, 0x0302_0302).unwrap();
+                manager.add_child(
+                    Data::new(manager.get_def(&0x0203_0203).unwrap().clone(), 0x0302_0203).unwrap(), &[]
+                ).unwrap();
+                manager.add_child(
+                    Data::new(manager.get_def(&0x0302_0302).unwrap().clone(), 0x0203).unwrap(),
+                    &[(0x0203_0203, None)]
+                ).unwrap();
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                let mut manager = new_manager(path, "test");
+                manager.read_def_from_file().unwrap();
+                assert_eq!(manager.get_def(&0x0203_0203).unwrap().get_child_tags(), vec![0x0302_0302]);
+                manager.read_data().unwrap();
+                let data = manager.get_data(&[(0x0203_0203, None), (0x0302_0302, None)]).unwrap();
+                assert_eq!(data.get::<i32>().unwrap(), 0x0203);
+            }
+
+            it "xxhash64 check sum" {
+                let path = &temp_dir("xxhash64");
+                let mut manager = new_manager(path, "test");
+                manager.set_checksum(ChecksumAlgorithm::XxHash64);
+                manager.add_def(0xabcd_abcd, "String test".to_string(), Type::String, false).unwrap();
+                manager.add_child(
+                    Data::new(manager.get_def(&0xabcd_abcd).unwrap().clone(), "taro".to_string()).unwrap(), &[]
+                ).unwrap();
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                read.read_data().unwrap();
+                assert_eq!(read.get_data_header().unwrap().checksum, ChecksumAlgorithm::XxHash64);
+                assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+            }
+
+            it "definition fingerprint" {
+                let path = &temp_dir("fingerprint");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0xabcd_abcd, "Int test".to_string(), Type::Int, false).unwrap();
+                manager.add_child(
+                    Data::new(manager.get_def(&0xabcd_abcd).unwrap().clone(), 3).unwrap(), &[]
+                ).unwrap();
+                manager.write_data().unwrap();
+                manager.read_data().unwrap();
+                let header = manager.get_data_header().unwrap();
+                assert_eq!(header.kind, FileKind::Data);
+                assert_eq!(header.def_fingerprint, Some(manager.def_fingerprint()));
+                // The canonical form of definitions does not change with the record format.
+                assert_eq!(manager.def_fingerprint(), 0xeede_599b_c063_1e97);
+
+                // The data file written at older format version is read by the same fingerprint.
+                let data_path = manager.path_manager.get_data_path().unwrap();
+                let mut bytes = std::fs::read(&data_path).unwrap();
+                // Check sum algorithm is recorded in file header since version 4.
+                bytes[MAGIC.len()] = 4;
+                std::fs::write(&data_path, bytes).unwrap();
+                manager.get_def_mut(&0xabcd_abcd).unwrap().set_explanation("changed".to_string());
+                manager.read_data().unwrap();
+                assert_eq!(manager.get_data_header().unwrap().version, 4);
+                assert_eq!(manager.get_data(&[(0xabcd_abcd, None)]).unwrap().get::<i32>().unwrap(), 3);
+
+                // Definitions are changed after the data file is written.
+                manager.get_def_mut(&0xabcd_abcd).unwrap().constraints.min = Some(Number::Float(0.0));
+                match manager.read_data() {
+                    Err(FractalError::DefinitionMismatch { actual, .. }) =>
+                        assert_eq!(actual, header_fingerprint(path)),
+                    _ => panic!("definition mismatch is not detected"),
+                }
+                manager.set_definition_check(DefinitionCheck::Warn);
+                manager.read_data().unwrap();
+                assert_eq!(manager.get_data(&[(0xabcd_abcd, None)]).unwrap().get::<i32>().unwrap(), 3);
+                assert!(matches!(manager.take_warnings().as_slice(), [FractalError::DefinitionMismatch { .. }]));
+            }
+
+            it "date time range" {
+                let path = &temp_dir("date_time_range");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0xabcd_abcd, "DateTime test".to_string(), Type::DateTime, true).unwrap();
+                let date = Date::from_ymd(2024, 1, 1).unwrap();
+                let nine = Time::from_hms_nano(9, 0, 0, 0).unwrap();
+                // 09:00+09:00 (00:00Z), 09:00+00:00 (09:00Z), 09:00-09:00 (18:00Z)
+                for offset in [9 * 3600, 0, -9 * 3600] {
+                    let value = DateTime::from_local(date, nine, offset).unwrap();
+                    manager.add_child(
+                        Data::new(manager.get_def(&0xabcd_abcd).unwrap().clone(), value).unwrap(), &[]
+                    ).unwrap();
+                }
+                let from = Value::from(DateTime::from_local(date, nine, 3600).unwrap());
+                let to = Value::from(DateTime::from_local(date, nine, -3600).unwrap());
+                let found = manager.find_range(&0xabcd_abcd, from..=to).unwrap();
+                assert_eq!(found.len(), 1);
+                assert_eq!(found[0].get::<DateTime>().unwrap().get_offset(), 0);
+            }
+
+            it "enumeration" {
+                let path = &temp_dir("enumeration");
+                let mut manager = new_manager(path, "test");
+                let members = vec![format!("active"), format!("closed")];
+                manager.add_enum_def(0xabcd_abcd, "Enum test".to_string(), members.clone(), true).unwrap();
+                for name in ["closed", "active"] {
+                    manager.add_child(
+                        Data::new(manager.get_def(&0xabcd_abcd).unwrap().clone(), name).unwrap(), &[]
+                    ).unwrap();
+                }
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                assert_eq!(read.get_def(&0xabcd_abcd).unwrap().get_members(), &members);
+                read.read_data().unwrap();
+                let found = read.find_range(&0xabcd_abcd, Value::Enum("closed".to_string())..=Value::Enum("closed".to_string()))
+                    .unwrap();
+                assert_eq!(found.len(), 1);
+                assert_eq!(found[0].get_value().unwrap(), Some(Value::Enum("closed".to_string())));
+            }
+
+            it "reference" {
+                let path = &temp_dir("reference");
+                let mut customer = new_manager(path, "customer");
+                customer.add_def(0x0001_0001, "Customer".to_string(), Type::String, true).unwrap();
+                customer.add_def(0x0001_0002, "Id".to_string(), Type::Int, false).unwrap();
+                customer.add_def(0x0002_0001, "Order".to_string(), Type::Reference, true).unwrap();
+                customer.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                for (name, id) in [("taro", 1), ("hanako", 2)] {
+                    let mut data = Data::new(customer.get_def(&0x0001_0001).unwrap().clone(), name).unwrap();
+                    let key = Data::new(customer.get_def(&0x0001_0002).unwrap().clone(), id).unwrap();
+                    data.add_child(&[], key).unwrap();
+                    customer.add_child(data, &[]).unwrap();
+                }
+                customer.write_data().unwrap();
+
+                // Order in other data name references customer.
+                let mut order = new_manager(path, "order");
+                order.def_list = customer.def_list.clone();
+                let key = Data::new(order.get_def(&0x0001_0002).unwrap().clone(), 2).unwrap();
+                let reference = Reference::new(Some("customer".to_string()), &[(0x0001_0001, Some(key))]).unwrap();
+                order.add_child(
+                    Data::new(order.get_def(&0x0002_0001).unwrap().clone(), reference.clone()).unwrap(), &[]
+                ).unwrap();
+                order.write_data().unwrap();
+                order.read_data().unwrap();
+                let data = order.get_data(&[(0x0002_0001, None)]).unwrap();
+                let resolved = order.resolve(&data.get::<Reference>().unwrap()).unwrap();
+                assert_eq!(resolved.get::<String>().unwrap(), format!("hanako"));
+                let ambiguous = Reference::new(Some("customer".to_string()), &[(0x0001_0001, None)]).unwrap();
+                assert!(matches!(order.resolve(&ambiguous), Err(FractalError::AmbiguousReference(_))));
+
+                // Dangling reference is detected on write and on read.
+                let key = Data::new(order.get_def(&0x0001_0002).unwrap().clone(), 3).unwrap();
+                let dangling = Reference::new(Some("customer".to_string()), &[(0x0001_0001, Some(key))]).unwrap();
+                order.add_child(
+                    Data::new(order.get_def(&0x0002_0001).unwrap().clone(), dangling).unwrap(), &[]
+                ).unwrap();
+                match order.write_data() {
+                    Err(FractalError::DanglingReference(_)) => (),
+                    _ => panic!("dangling reference is not detected on write"),
+                }
+                customer.parent_data.clear_children();
+                customer.write_data().unwrap();
+                match order.read_data() {
+                    Err(FractalError::DanglingReference(reference)) =>
+                        assert_eq!(reference, format!("customer/00010001[00010002=02000000]")),
+                    _ => panic!("dangling reference is not detected on read"),
+                }
+            }
+
+            it "remove, rename and retag" {
+                let path = &temp_dir("schema_change");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Order".to_string(), Type::Int, true).unwrap();
+                manager.add_def(0x0001_0002, "Item".to_string(), Type::String, true).unwrap();
+                manager.add_def(0x0001_0003, "Note".to_string(), Type::String, false).unwrap();
+                manager.add_def(0x0001_0004, "Link".to_string(), Type::Reference, true).unwrap();
+                manager.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                manager.add_def_child(&0x0001_0001, 0x0001_0003).unwrap();
+                let item = manager.get_def(&0x0001_0002).unwrap().clone();
+                for (id, items) in [(1, vec!["a", "b"]), (2, vec!["c"])] {
+                    let mut data = Data::new(manager.get_def(&0x0001_0001).unwrap().clone(), id).unwrap();
+                    for name in items {
+                        data.add_child(&[], Data::new(item.clone(), name).unwrap()).unwrap();
+                    }
+                    manager.add_child(data, &[]).unwrap();
+                }
+                let note = Data::new(manager.get_def(&0x0001_0003).unwrap().clone(), "n").unwrap();
+                manager.add_child(note, &[(0x0001_0001, None)]).unwrap();
+                let root = [(0x0001_0001, Some(Data::new(item, "c").unwrap())), (0x0001_0002, None)];
+                let link = Data::new(manager.get_def(&0x0001_0004).unwrap().clone(), Reference::new(None, &root).unwrap()).unwrap();
+                manager.add_child(link, &[]).unwrap();
+
+                // Dry run lists the data and the references which use the tag.
+                let affected = manager.retag_def(&0x0001_0002, 0x0001_0009, DataMigration::DryRun).unwrap();
+                assert_eq!(affected, vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![2]]);
+                assert!(manager.get_def(&0x0001_0002).is_ok());
+                assert!(matches!(manager.retag_def(&0x0001_0002, 0x0001_0009, DataMigration::Reject),
+                    Err(FractalError::DefinitionInUse(0x0001_0002))));
+                assert!(matches!(manager.retag_def(&0x0001_0002, 0x0001_0003, DataMigration::Migrate),
+                    Err(FractalError::DuplicateTag(0x0001_0003))));
+
+                // References to other data names are listed, but they are not migrated.
+                let other = Reference::new(Some("other".to_string()), &root).unwrap();
+                let link = Data::new(manager.get_def(&0x0001_0004).unwrap().clone(), other).unwrap();
+                manager.add_child(link, &[]).unwrap();
+                let affected = manager.retag_def(&0x0001_0002, 0x0001_0009, DataMigration::DryRun).unwrap();
+                assert_eq!(affected, vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![2], vec![3]]);
+                assert!(matches!(manager.retag_def(&0x0001_0002, 0x0001_0009, DataMigration::Migrate),
+                    Err(FractalError::DefinitionInUse(0x0001_0002))));
+                assert!(manager.get_def(&0x0001_0002).is_ok());
+                manager.parent_data.remove_child_by_index(&[3]);
+                manager.retag_def(&0x0001_0002, 0x0001_0009, DataMigration::Migrate).unwrap();
+                assert_eq!(manager.get_def(&0x0001_0001).unwrap().get_child_tags(), vec![0x0001_0009, 0x0001_0003]);
+                let data = manager.get_data(&[(0x0001_0001, None), (0x0001_0009, None)]).unwrap();
+                assert_eq!((data.get_def().tag, data.get::<String>().unwrap()), (0x0001_0009, "a".to_string()));
+                let link = manager.get_data(&[(0x0001_0004, None)]).unwrap().get::<Reference>().unwrap();
+                assert_eq!(manager.resolve(&link).unwrap().get::<String>().unwrap(), format!("c"));
+                assert!(manager.validate().is_empty());
+
+                assert!(matches!(manager.rename_def(&0x0001_0003, "Item".to_string(), DataMigration::Migrate),
+                    Err(FractalError::DuplicateName(_))));
+                assert_eq!(manager.rename_def(&0x0001_0003, "Memo".to_string(), DataMigration::Migrate).unwrap(), vec![vec![0, 2]]);
+                let data = manager.get_data(&[(0x0001_0001, None), (0x0001_0003, None)]).unwrap();
+                assert_eq!(data.get_def().get_name(), &"Memo".to_string());
+
+                assert!(matches!(manager.remove_def(&0x0000_0000, DataMigration::Migrate), Err(FractalError::DefinitionInUse(0))));
+                assert!(matches!(manager.remove_def(&0x0001_0003, DataMigration::Reject), Err(FractalError::DefinitionInUse(_))));
+                assert_eq!(manager.remove_def(&0x0001_0003, DataMigration::Migrate).unwrap(), vec![vec![0, 2]]);
+                assert!(matches!(manager.get_def(&0x0001_0003), Err(FractalError::UnknownTag(_))));
+                assert_eq!(manager.get_def(&0x0001_0001).unwrap().get_child_tags(), vec![0x0001_0009]);
+                assert_eq!(manager.get_data(&[(0x0001_0001, None)]).unwrap().get_children().len(), 2);
+
+                // The changed definitions and data are written and read.
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                read.read_data().unwrap();
+                assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+            }
+
+            it "nearest vector" {
+                let path = &temp_dir("nearest_vector");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Item".to_string(), Type::Int, true).unwrap();
+                manager.add_vector_def(0x0001_0002, "Embedding".to_string(), 2, false).unwrap();
+                manager.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                for i in 0..50 {
+                    let mut item = Data::new(manager.get_def(&0x0001_0001).unwrap().clone(), i).unwrap();
+                    let vector = vec![(i % 10) as f32, (i / 10) as f32 + 1.0];
+                    item.add_child(&[], Data::new(manager.get_def(&0x0001_0002).unwrap().clone(), vector).unwrap())
+                        .unwrap();
+                    manager.add_child(item, &[]).unwrap();
+                }
+                let found = manager.nearest(&0x0001_0002, &[2.9, 3.1], 2, Distance::L2).unwrap();
+                assert_eq!(found.len(), 2);
+                assert_eq!(found[0].root.as_ref().unwrap()[0].0, 0x0001_0001);
+                let data = manager.get_data(found[0].root.as_ref().unwrap()).unwrap();
+                assert_eq!(data.get::<Vec<f32>>().unwrap(), vec![3.0, 3.0]);
+                assert_eq!(manager.get_data_by_index(&found[0].index), Some(data));
+                let found = manager.nearest_exact(&0x0001_0002, &[1.0, 3.0], 1, Distance::Cosine).unwrap();
+                assert_eq!(found[0].distance, 0.0);
+
+                // Embeddings under the same parent are found by index path.
+                manager.add_def(0x0001_0003, "Document".to_string(), Type::Int, true).unwrap();
+                manager.add_vector_def(0x0001_0004, "Chunk".to_string(), 2, true).unwrap();
+                manager.add_def_child(&0x0001_0003, 0x0001_0004).unwrap();
+                let mut document = Data::new(manager.get_def(&0x0001_0003).unwrap().clone(), 0).unwrap();
+                for vector in [vec![0.0_f32, 0.0], vec![5.0, 5.0], vec![9.0, 9.0]] {
+                    document.add_child(&[], Data::new(manager.get_def(&0x0001_0004).unwrap().clone(), vector).unwrap())
+                        .unwrap();
+                }
+                manager.add_child(document, &[]).unwrap();
+                let found = manager.nearest_exact(&0x0001_0004, &[9.0, 8.0], 1, Distance::L2).unwrap();
+                assert_eq!((found[0].index.clone(), found[0].root.clone()), (vec![50, 2], None));
+                let data = manager.get_data_by_index(&found[0].index).unwrap();
+                assert_eq!(data.get::<Vec<f32>>().unwrap(), vec![9.0, 9.0]);
+                match manager.nearest(&0x0001_0002, &[1.0], 1, Distance::L2) {
+                    Err(FractalError::DimensionMismatch { expected, .. }) => assert_eq!(expected, 2),
+                    _ => panic!("dimension mismatch is not detected"),
+                }
+
+                // Index is rebuilt on read_data.
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                assert_eq!(read.get_def(&0x0001_0002).unwrap().dimension, 2);
+                read.set_vector_index(0x0001_0002, Distance::L2).unwrap();
+                read.read_data().unwrap();
+                assert!(read.vector_indexes.get().unwrap().contains_key(&0x0001_0002));
+                let found = read.nearest(&0x0001_0002, &[2.9, 3.1], 2, Distance::L2).unwrap();
+                let exact = read.nearest_exact(&0x0001_0002, &[2.9, 3.1], 2, Distance::L2).unwrap();
+                assert_eq!(found, exact);
+
+                // Index is built again on search after data is added.
+                let mut item = Data::new(read.get_def(&0x0001_0001).unwrap().clone(), 50).unwrap();
+                item.add_child(&[], Data::new(read.get_def(&0x0001_0002).unwrap().clone(), vec![2.9_f32, 3.1]).unwrap())
+                    .unwrap();
+                read.add_child(item, &[]).unwrap();
+                assert!(read.vector_indexes.get().is_none());
+                let found = read.nearest(&0x0001_0002, &[2.9, 3.1], 1, Distance::L2).unwrap();
+                assert_eq!((found[0].index.clone(), found[0].distance), (vec![51, 0], 0.0));
+                assert!(read.vector_indexes.get().unwrap().contains_key(&0x0001_0002));
+            }
+
+            it "spatial query" {
+                let path = &temp_dir("spatial_query");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Location".to_string(), Type::Point, true).unwrap();
+                for i in 0..400 {
+                    let point = Point::new((i / 20) as f64 - 10.0, (i % 20) as f64 - 10.0).unwrap();
+                    manager.add_child(
+                        Data::new(manager.get_def(&0x0001_0001).unwrap().clone(), point).unwrap(), &[]
+                    ).unwrap();
+                }
+                let area = BoundingBox::new(Point::new(-0.5, -0.5).unwrap(), Point::new(1.5, 1.5).unwrap()).unwrap();
+                let scanned = manager.find_in_box(&0x0001_0001, &area).unwrap();
+                assert_eq!(scanned.len(), 4);
+                // About 111 km per degree.
+                let center = Point::new(0.0, 0.0).unwrap();
+                assert_eq!(manager.find_in_radius(&0x0001_0001, &center, 120_000.0).unwrap().len(), 5);
+
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                read.set_spatial_index(0x0001_0001).unwrap();
+                read.read_data().unwrap();
+                assert!(read.spatial_indexes.get().unwrap().contains_key(&0x0001_0001));
+                assert_eq!(read.find_in_box(&0x0001_0001, &area).unwrap(), scanned);
+                assert_eq!(read.find_in_radius(&0x0001_0001, &center, 120_000.0).unwrap().len(), 5);
+
+                // Index is built again on search after data is added.
+                let point = Point::new(1.0, 1.0).unwrap();
+                read.add_child(Data::new(read.get_def(&0x0001_0001).unwrap().clone(), point).unwrap(), &[]).unwrap();
+                assert!(read.spatial_indexes.get().is_none());
+                assert_eq!(read.find_in_box(&0x0001_0001, &area).unwrap().len(), 5);
+                assert!(read.spatial_indexes.get().unwrap().contains_key(&0x0001_0001));
+                assert!(read.set_spatial_index(0x0000_0000).is_err());
+            }
+
+            it "custom type" {
+                let path = &temp_dir("custom_type");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0xabcd_abcd, "Version".to_string(), Type::Custom(0x80), true).unwrap();
+                manager.register_codec(0x80, VersionCodec).unwrap();
+                for version in ["1.9.0", "1.10.0", "2.0.0"] {
+                    manager.add_child(
+                        Data::new(manager.get_def(&0xabcd_abcd).unwrap().clone(), version).unwrap(), &[]
+                    ).unwrap();
+                }
+                // Versions are compared by the codec, not as strings.
+                let found = manager.find_range(&0xabcd_abcd, Value::from("1.9.5")..Value::from("2.0.0")).unwrap();
+                assert_eq!(found.len(), 1);
+                assert_eq!(found[0].get::<String>().unwrap(), format!("1.10.0"));
+                // Keys are matched by the codec, not as bytes.
+                manager.add_def(0xabcd_0001, "Release".to_string(), Type::String, true).unwrap();
+                manager.add_def_child(&0xabcd_0001, 0xabcd_abcd).unwrap();
+                let version = manager.get_def(&0xabcd_abcd).unwrap().clone();
+                let mut release = Data::new(manager.get_def(&0xabcd_0001).unwrap().clone(), "stable").unwrap();
+                release.add_child(&[], Data::new(version.clone(), "2.0.0").unwrap()).unwrap();
+                manager.add_child(release, &[]).unwrap();
+                let key = Data::new(version, "2.0").unwrap();
+                let found = manager.get_data(&[(0xabcd_0001, Some(key))]).unwrap();
+                assert_eq!(found.get::<String>().unwrap(), format!("stable"));
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                match read.read_data() {
+                    Err(FractalError::UnregisteredCodec(code)) => assert_eq!(code, 0x80),
+                    _ => panic!("unregistered codec is not detected"),
+                }
+                read.register_codec(0x80, VersionCodec).unwrap();
+                read.read_data().unwrap();
+                assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+                assert!(read.register_codec(0x10, VersionCodec).is_err());
+            }
+
+            it "constraints" {
+                let path = &temp_dir("constraints");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Code".to_string(), Type::String, true).unwrap();
+                manager.add_def(0x0001_0002, "Count".to_string(), Type::Int, false).unwrap();
+                manager.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                let plain = manager.get_def(&0x0001_0002).unwrap().clone();
+                let code = manager.get_def_mut(&0x0001_0001).unwrap();
+                code.constraints.set_pattern(Some("[A-Z]{3}".to_string())).unwrap();
+                code.constraints.not_empty = true;
+                let count = manager.get_def_mut(&0x0001_0002).unwrap();
+                count.constraints.min = Some(Number::Int(1));
+                count.constraints.max = Some(Number::Float(9.0));
+                let code = manager.get_def(&0x0001_0001).unwrap().clone();
+                let count = manager.get_def(&0x0001_0002).unwrap().clone();
+                assert!(matches!(Data::new(code.clone(), "abc"), Err(FractalError::PatternMismatch(0x0001_0001))));
+                assert!(matches!(Data::new(count.clone(), 10), Err(FractalError::ValueOutOfRange(0x0001_0002))));
+                // Data made from old definition is checked by add_child.
+                let mut data = Data::new(code.clone(), "ABC").unwrap();
+                data.add_child(&[], Data::new(plain.clone(), 0).unwrap()).unwrap();
+                assert!(matches!(manager.add_child(data, &[]), Err(FractalError::ValueOutOfRange(0x0001_0002))));
+                let mut data = Data::new(code.clone(), "ABC").unwrap();
+                data.add_child(&[], Data::new(count.clone(), 3).unwrap()).unwrap();
+                manager.add_child(data, &[]).unwrap();
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                assert_eq!(read.get_def(&0x0001_0001).unwrap().constraints, code.constraints);
+                assert_eq!(read.get_def(&0x0001_0002).unwrap().constraints, count.constraints);
+                read.read_data().unwrap();
+
+                // The file written without constraints is reported.
+                let mut loose = new_manager(path, "test");
+                loose.add_def(0x0001_0001, "Code".to_string(), Type::String, true).unwrap();
+                loose.add_def(0x0001_0002, "Count".to_string(), Type::Int, false).unwrap();
+                loose.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                let mut data = Data::new(loose.get_def(&0x0001_0001).unwrap().clone(), "ABC").unwrap();
+                data.add_child(&[], Data::new(plain, 0).unwrap()).unwrap();
+                loose.add_child(data, &[]).unwrap();
+                loose.write_data().unwrap();
+                read.set_definition_check(DefinitionCheck::Warn);
+                assert!(matches!(read.read_data(), Err(FractalError::ValueOutOfRange(0x0001_0002))));
+                let data = read.get_data(&[(0x0001_0001, None), (0x0001_0002, None)]).unwrap();
+                assert_eq!(data.get::<i32>().unwrap(), 3);
+            }
+
+            it "null and missing" {
+                let path = &temp_dir("null_and_missing");
+                let mut manager = new_manager(path, "test");
+                manager.add_def(0x0001_0001, "Person".to_string(), Type::String, true).unwrap();
+                manager.add_def(0x0001_0002, "Age".to_string(), Type::Int, false).unwrap();
+                manager.get_def_mut(&0x0001_0002).unwrap().set_nullable(true);
+                manager.add_def_child(&0x0001_0001, 0x0001_0002).unwrap();
+                let age = manager.get_def(&0x0001_0002).unwrap().clone();
+                for (name, child) in [("taro", Some(Data::new(age.clone(), 20).unwrap())),
+                                      ("jiro", Some(Data::null(age.clone()).unwrap())), ("saburo", None)] {
+                    let mut person = Data::new(manager.get_def(&0x0001_0001).unwrap().clone(), name).unwrap();
+                    if let Some(child) = child {
+                        person.add_child(&[], child).unwrap();
+                    }
+                    manager.add_child(person, &[]).unwrap();
+                }
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                assert!(read.get_def(&0x0001_0002).unwrap().is_nullable);
+                read.read_data().unwrap();
+                assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+                for (presence, name) in [(Presence::Value, "taro"), (Presence::Null, "jiro"), (Presence::Missing, "saburo")] {
+                    let found = read.find_by_presence(&0x0001_0001, &0x0001_0002, presence).unwrap();
+                    assert_eq!(found.len(), 1);
+                    assert_eq!(found[0].get::<String>().unwrap(), format!("{}", name));
+                }
+                // Null is not in any range.
+                assert_eq!(read.find_range(&0x0001_0002, ..).unwrap().len(), 1);
+            }
+
+            it "blob" {
+                let path = &temp_dir("blob");
+                let mut manager = new_manager(path, "test");
+                manager.set_blob_threshold(4);
+                manager.add_def(0xabcd_abcd, "Bytes test".to_string(), Type::Bytes, true).unwrap();
+                let large: Vec<u8> = (0..16).collect();
+                for value in [large.clone(), vec![0x02, 0x03]] {
+                    manager.add_child(
+                        Data::new(manager.get_def(&0xabcd_abcd).unwrap().clone(), value).unwrap(), &[]
+                    ).unwrap();
+                }
+                manager.write_def().unwrap();
+                manager.write_data().unwrap();
+
+                let mut read = new_manager(path, "test");
+                read.read_def_from_file().unwrap();
+                read.read_data().unwrap();
+                assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+                let found = read.get_data(&[]).unwrap().find_by_tag(0xabcd_abcd);
+                // Only the payload over the threshold is stored in blob file.
+                let blob = found[0].get_blob().unwrap().clone();
+                assert_eq!(blob.len, 16);
+                assert_eq!(blob.path, manager.path_manager.get_blob_path(blob.hash).unwrap());
+                assert_eq!(found[1].get_blob(), None);
+                let mut vec = Vec::new();
+                found[0].open_bytes().unwrap().read_to_end(&mut vec).unwrap();
+                assert_eq!(vec, large);
+                assert_eq!(found[1].get::<Vec<u8>>().unwrap(), vec![0x02, 0x03]);
+
+                // Broken blob of the same length is written again, and unused blob is removed.
+                std::fs::write(&blob.path, [0xff; 16]).unwrap();
+                let unused = manager.path_manager.get_blob_path(0xaa).unwrap();
+                std::fs::write(&unused, [0x00]).unwrap();
+                manager.write_data().unwrap();
+                assert_eq!(std::fs::read(&blob.path).unwrap(), large);
+                assert!(!unused.exists());
+                // The broken blob which the data refers to cannot be written again.
+                std::fs::write(&blob.path, [0xff; 16]).unwrap();
+                assert!(matches!(read.write_data(), Err(FractalError::BlobMismatch(_))));
+                manager.write_data().unwrap();
+
+                // Blob is copied when the data is written in other data name.
+                read.path_manager = PathManager::new(path.to_path_buf(), "test".to_string(), "copy".to_string());
+                read.write_data().unwrap();
+                read.read_data().unwrap();
+                assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+                let found = read.get_data(&[]).unwrap().find_by_tag(0xabcd_abcd);
+                assert_eq!(found[0].get::<Vec<u8>>().unwrap(), large);
+
+                // Broken blob is detected when it is read.
+                std::fs::write(&found[0].get_blob().unwrap().path, [0x00]).unwrap();
+                match found[0].get_value() {
+                    Err(FractalError::BlobMismatch(hash)) => assert_eq!(hash, blob.hash),
+                    _ => panic!("broken blob is not detected"),
+                }
+            }
+
+            it "round trip" {
+                let path = &temp_dir("round_trip");
+                let mut runner = TestRunner::new(Config::with_cases(64));
+                runner.run(&(def_strategy(), vec(node_strategy(), 0..4), 0_usize..32), |(defs, nodes, threshold)| {
+                    let mut manager = new_manager(path, "test");
+                    manager.set_blob_threshold(threshold);
+                    manager.register_codec(0x80, VersionCodec).unwrap();
+                    for def in &defs {
+                        manager.add_def(def.tag, def.name.clone(), def.data_type, def.is_multiple).unwrap();
+                        let d = manager.get_def_mut(&def.tag).unwrap();
+                        d.explanation = def.explanation.clone();
+                        d.children = def.children.clone();
+                        d.members = def.members.clone();
+                        d.dimension = def.dimension;
+                        d.scale = def.scale;
+                        d.is_nullable = def.is_nullable;
+                        d.is_base = def.is_base;
+                        d.base = def.base;
+                        d.set_recursive(def.is_recursive, def.max_depth);
+                        d.constraints = def.constraints.clone();
+                        d.default_value = def.default_value.clone();
+                        // The value of user defined type is encoded by the registered codec.
+                        if let Type::Custom(_) = def.data_type {
+                            d.set_default(format!("{}.{}", def.tag >> 16, def.tag & 0xffff)).unwrap();
+                        }
+                    }
+                    // Data is built from the definitions which have the codec.
+                    let defs: Vec<Definition> = defs.iter().map(|def| manager.get_def(&def.tag).unwrap().clone()).collect();
+                    let target = reference_target(&defs, &nodes);
+                    // Random data does not follow the children of definitions.
+                    manager.set_child_check(ChildCheck::Allow);
+                    for node in &nodes {
+                        manager.add_child(build_data(&defs, node, &target), &[]).unwrap();
+                    }
+                    manager.write_def().unwrap();
+                    manager.write_data().unwrap();
+
+                    let mut read = new_manager(path, "test");
+                    read.register_codec(0x80, VersionCodec).unwrap();
+                    // Random definitions may have cycles.
+                    read.set_definition_check(DefinitionCheck::Warn);
+                    read.read_def_from_file().unwrap();
+                    read.read_data().unwrap();
+                    prop_assert_eq!(read.get_def_tag_list(), manager.get_def_tag_list());
+                    for tag in manager.get_def_tag_list() {
+                        prop_assert_eq!(read.get_def(&tag).unwrap(), manager.get_def(&tag).unwrap());
+                    }
+                    prop_assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+
+                    // Reading again replaces the data.
+                    read.read_data().unwrap();
+                    prop_assert_eq!(read.get_data(&[]).unwrap(), manager.get_data(&[]).unwrap());
+                    Ok(())
+                }).unwrap();
+            }
+        }
+
+        use crate::manager::io_for_db::header::header::FileKind;
+        use crate::manager::io_for_db::io::MAGIC;
+        use std::cmp::Ordering;
+        use std::io::Read;
+        use crate::manager::data::temporal::temporal::{Date, Time, DateTime, NANOS_PER_DAY};
+        use crate::manager::data::decimal::decimal::Decimal;
+        use crate::manager::data::uuid::uuid::Uuid;
+
+        /// Semantic version "major.minor.patch" stored as three u16 (big endian).
+        struct VersionCodec;
+
+        impl ValueCodec for VersionCodec {
+            fn name(&self) -> &str {
+                "version"
+            }
+
+            fn encode(&self, value: &Value) -> Result<Vec<u8>> {
+                let invalid = FractalError::InvalidValue(Type::Custom(0x80));
+                let parts: Vec<u16> = match value.as_str() {
+                    Some(text) => text.split('.').map(|e| e.parse::<u16>()).collect::<std::result::Result<_, _>>()
+                        .map_err(|_| invalid)?,
+                    None => return Err(invalid),
+                };
+                Ok(parts.iter().flat_map(|e| e.to_be_bytes()).collect())
+            }
+
+            fn decode(&self, bytes: &[u8]) -> Result<Value> {
+                let parts: Vec<String> = bytes.chunks(2)
+                    .map(|e| u16::from_be_bytes([e[0], e[1]]).to_string())
+                    .collect();
+                Ok(Value::String(parts.join(".")))
+            }
+
+            /// Missing parts are zero, so "1.10" is the same as "1.10.0".
+            fn compare(&self, a: &[u8], b: &[u8]) -> Option<Ordering> {
+                let part = |bytes: &[u8], i: usize| bytes.get(i * 2..i * 2 + 2).map_or(0, |e| u16::from_be_bytes([e[0], e[1]]));
+                let len = a.len().max(b.len()) / 2;
+                Some((0..len).map(|i| part(a, i).cmp(&part(b, i))).find(|o| o.is_ne()).unwrap_or(Ordering::Equal))
+            }
+        }
+
+        /// Read definition fingerprint in the header of data file.
+        fn header_fingerprint(path: &std::path::Path) -> u64 {
+            let mut b = BinaryManager::new();
+            let data_path = PathManager::new(path.to_path_buf(), "test".to_string(), "test".to_string())
+                .get_data_path().unwrap();
+            b.read_to_end(&data_path).unwrap().def_fingerprint.unwrap()
+        }
+
+        use proptest::collection::vec;
+        use proptest::prelude::*;
+        use proptest::test_runner::{Config, TestRunner};
+
+        /// Random data node. Definition and value are chosen when the data is built.
+        #[derive(Clone, Debug)]
+        struct NodeSeed {
+            def: usize,
+            bits: u64,
+            string: String,
+            children: Vec<NodeSeed>,
+        }
+
+        /// Random type. User defined type 0x80 is registered with `VersionCodec`.  
+        /// Bytes and references are frequent because they are stored in blob files and resolved on write.
+        fn type_strategy() -> impl Strategy<Value = Type> {
+            prop_oneof![
+                6 => (0_u8..=0x1a).prop_map(|code| Type::u8_to_type(code).unwrap()),
+                1 => Just(Type::Bytes),
+                1 => Just(Type::Reference),
+                1 => Just(Type::Custom(0x80)),
+            ]
+        }
+
+        /// Random definition graph. Tag 0 is reserved for the parent tag.  
+        /// The definition extends the last preceding base definition if the tag has bit 0x80, so base links have no cycle.
+        fn def_strategy() -> impl Strategy<Value = Vec<Definition>> {
+            proptest::collection::btree_set(1_u32.., 1..6).prop_flat_map(|tags| {
+                let tags: Vec<u32> = tags.into_iter().collect();
+                let defs: Vec<_> = tags.iter().map(|tag| (
+                    Just(*tag), ".*", type_strategy(), any::<bool>(), ".*",
+                    proptest::sample::subsequence(tags.clone(), 0..=tags.len()),
+                    proptest::collection::btree_set(".*", 1..4),
+                )).collect();
+                defs.prop_map(|defs| {
+                    let mut built: Vec<Definition> = Vec::new();
+                    for (tag, name, data_type, is_multiple, explanation, children, members) in defs {
+                        let base = built.iter().rev().find(|def| def.is_base).filter(|_| tag & 0x80 != 0);
+                        let data_type = base.map_or(data_type, |base| base.data_type);
+                        // The tag makes the name unique.
+                        let mut def = Definition::new(tag, format!("{}{:08x}", name, tag), data_type, is_multiple);
+                        def.base = base.map(|base| base.tag);
+                        def.explanation = explanation;
+                        def.children = children.into_iter()
+                            .map(|child| ChildDef::with_range(child, child % 3, Some(child % 3 + 2).filter(|_| child & 1 == 0)))
+                            .collect();
+                        if data_type == Type::Enum {
+                            def.members = members.into_iter().collect();
+                        }
+                        if data_type == Type::Vector {
+                            def.dimension = tag % 4;
+                        }
+                        if data_type == Type::Decimal {
+                            def.scale = (tag % 5) as u8;
+                        }
+                        def.is_nullable = tag & 0x10 != 0;
+                        def.is_base = tag & 0x20 != 0;
+                        def.is_recursive = tag & 0x40 != 0;
+                        // Generated data is not nested deeper than 4.
+                        def.max_depth = Some(tag % 3 + 4).filter(|_| tag & 0x100 != 0);
+                        match data_type {
+                            Type::Int => def.set_default(tag as i32).unwrap(),
+                            Type::I64 => def.set_default(-i64::from(tag) << 31).unwrap(),
+                            Type::U64 => def.set_default(u64::MAX - u64::from(tag)).unwrap(),
+                            Type::F64 => def.set_default(f64::from(tag) / 3.0).unwrap(),
+                            Type::Float => def.set_default(tag as f32 / 3.0).unwrap(),
+                            Type::Byte => def.set_default(tag as u8).unwrap(),
+                            _ => (),
+                        }
+                        // Constraints which every generated value satisfies.
+                        let c = &mut def.constraints;
+                        match data_type {
+                            Type::String => {
+                                c.max_len = Some(u32::MAX - tag % 7);
+                                c.set_pattern(Some("(?s).*".to_string())).unwrap();
+                            },
+                            Type::Int => (c.min, c.max) = (Some(Number::from(i32::MIN)), Some(Number::from(i32::MAX))),
+                            Type::I64 => (c.min, c.max) = (Some(Number::from(i64::MIN)), Some(Number::from(i64::MAX))),
+                            Type::U32 => (c.min, c.max) = (Some(Number::Int(0)), Some(Number::Int(i128::from(u32::MAX)))),
+                            Type::U64 => (c.min, c.max) = (Some(Number::Int(0)), Some(Number::from(u64::MAX))),
+                            Type::Byte => c.max = Some(Number::Int(255)),
+                            // NaN is out of any range, so the value of constrained definition is not NaN.
+                            Type::Float | Type::F64 => (c.min, c.max) = (Some(Number::Float(f64::NEG_INFINITY)), Some(Number::Float(f64::INFINITY))),
+                            _ => (),
+                        }
+                        built.push(def);
+                    }
+                    built
+                })
+            })
+        }
+
+        fn node_strategy() -> impl Strategy<Value = NodeSeed> {
+            let leaf = (any::<usize>(), any::<u64>(), ".*").prop_map(
+                |(def, bits, string)| NodeSeed { def, bits, string, children: Vec::new() }
+            );
+            leaf.prop_recursive(3, 16, 3, |inner| {
+                (any::<usize>(), any::<u64>(), ".*", vec(inner, 0..3)).prop_map(
+                    |(def, bits, string, children)| NodeSeed { def, bits, string, children }
+                )
+            })
+        }
+
+        /// Path to the data which reference values point to.  
+        /// Each step selects the first seed whose tag is unique among its siblings, so the path resolves to one data.
+        fn reference_target(defs: &[Definition], nodes: &[NodeSeed]) -> Vec<(u32, Option<Data>)> {
+            let mut target = Vec::new();
+            let mut siblings = nodes;
+            loop {
+                let tags: Vec<u32> = siblings.iter().map(|node| defs[node.def % defs.len()].tag).collect();
+                match siblings.iter().zip(&tags).find(|(_, tag)| tags.iter().filter(|e| e == tag).count() == 1) {
+                    Some((node, tag)) => {
+                        target.push((*tag, None));
+                        siblings = &node.children;
+                    },
+                    None => return target,
+                }
+            }
+        }
+
+        fn build_data(defs: &[Definition], node: &NodeSeed, target: &DataRoot) -> Data {
+            let def = defs[node.def % defs.len()].clone();
+            let value = match def.data_type {
+                Type::Int => Value::Int(node.bits as i32),
+                Type::Float => Value::Float(Some(f32::from_bits(node.bits as u32))
+                    .filter(|value| !value.is_nan() || def.constraints.is_empty()).unwrap_or(0.0)),
+                Type::String => Value::String(node.string.clone()),
+                Type::Bool => Value::Bool(node.bits & 1 == 1),
+                Type::I64 => Value::I64(node.bits as i64),
+                Type::U32 => Value::U32(node.bits as u32),
+                Type::U64 => Value::U64(node.bits),
+                Type::F64 => Value::F64(Some(f64::from_bits(node.bits))
+                    .filter(|value| !value.is_nan() || def.constraints.is_empty()).unwrap_or(0.0)),
+                Type::Byte => Value::Byte(node.bits as u8),
+                Type::Date => Value::Date(Date::from_days(node.bits as i32)),
+                Type::Time => Value::Time(Time::from_nanos(node.bits % NANOS_PER_DAY).unwrap()),
+                Type::DateTime => Value::DateTime(DateTime::new(node.bits as i64, (node.bits >> 48) as i16 as i32).unwrap()),
+                Type::Duration => Value::Duration(std::time::Duration::new(node.bits, (node.bits % 1_000_000_000) as u32)),
+                Type::Bytes => Value::Bytes(node.string.as_bytes().to_vec()),
+                Type::Enum => Value::Enum(def.members[node.bits as usize % def.members.len()].clone()),
+                // The data name of this manager is the same as no data name.
+                Type::Reference => Value::Reference(
+                    Reference::new(Some("test".to_string()).filter(|_| node.bits & 1 == 1), &target[..node.bits as usize % (target.len() + 1)]).unwrap()
+                ),
+                Type::IntArray => Value::IntArray(node.string.bytes().map(|e| i32::from(e).wrapping_mul(node.bits as i32)).collect()),
+                Type::FloatArray => Value::FloatArray(node.string.bytes().map(|e| f32::from(e) * node.bits as f32).collect()),
+                Type::I64Array => Value::I64Array(node.string.bytes().map(|e| i64::from(e).wrapping_mul(node.bits as i64)).collect()),
+                Type::U32Array => Value::U32Array(node.string.bytes().map(|e| u32::from(e) ^ node.bits as u32).collect()),
+                Type::U64Array => Value::U64Array(node.string.bytes().map(|e| u64::from(e) ^ node.bits).collect()),
+                Type::Vector => Value::Vector((0..def.dimension).map(|i| f32::from_bits((node.bits >> i) as u32)).collect()),
+                Type::Point => Value::Point(Point::new((node.bits % 181) as f64 - 90.0, ((node.bits >> 8) % 361) as f64 - 180.0).unwrap()),
+                Type::BoundingBox => Value::BoundingBox(BoundingBox::around(&Point::new(0.0, (node.bits % 360) as f64 - 180.0).unwrap(), node.bits as f64)),
+                Type::Decimal => Value::Decimal(Decimal::new(i128::from(node.bits as i64), def.scale).unwrap()),
+                Type::Uuid => Value::Uuid(Uuid::from_bytes((u128::from(node.bits) << 64 | u128::from(!node.bits)).to_be_bytes())),
+                Type::Custom(_) => Value::String(format!("{}.{}.{}", node.bits as u16, (node.bits >> 16) as u16, (node.bits >> 32) as u16)),
+                Type::F64Array => Value::F64Array(node.string.bytes().map(|e| f64::from(e) * node.bits as f64).collect()),
+            };
+            let mut data = if def.is_nullable && node.bits.is_multiple_of(3) {
+                Data::null(def).unwrap()
+            }
+            else {
+                Data::new(def, value).unwrap()
+            };
+            for child in &node.children {
+                data.add_child(&[], build_data(defs, child, target)).unwrap();
             }
+            data
         }
     }
 }
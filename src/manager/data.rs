@@ -1,60 +1,97 @@
 
 pub mod definition;
+pub mod value;
+pub mod temporal;
+pub mod blob;
+pub mod reference;
+pub mod vector;
+pub mod geo;
+pub mod codec;
+pub mod decimal;
+pub mod uuid;
+pub mod constraint;
 
-use definition::definition::{Definition, Type};
-
+#[allow(clippy::module_inception)]
 pub mod data {
 
-    use crate::manager::data::definition::definition::{Definition, Type};
-
-    use std::error;
-    use std::any::{Any, TypeId};
+    use crate::manager::data::definition::definition::Definition;
+    use crate::manager::data::definition::definition::Type;
+    use crate::manager::data::value::value::{Value, FromValue};
+    use crate::manager::data::blob::blob::{Blob, content_hash};
+    use crate::manager::data::decimal::decimal::Decimal;
+    use crate::manager::data::uuid::uuid::Uuid;
+    use crate::manager::error::error::{FractalError, Result};
+    use std::cmp::Ordering;
+    use std::collections::HashMap;
+    use std::io::{Cursor, Read};
 
-    // Change the alias to `Box<dyn error::Error>`.
-    type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
     /// Data root type. It use by search and/or specify data.
     pub type DataRoot = [(u32, Option<Data>)];
 
+    /// Data node. It has a value and children.
+    #[derive(Clone, Debug)]
     pub struct Data {
         def: Definition,
         value: Vec<u8>,
+        blob: Option<Blob>,
+        is_null: bool,
         children: Vec<Data>,
     }
 
+    /// Data is compared by definition, value and children.  
+    /// The value stored in blob file is compared by content hash and length, so it is equal to the same inline value.
+    impl PartialEq for Data {
+        fn eq(&self, other: &Data) -> bool {
+            self.def == other.def && self.has_same_value(other) && self.children == other.children
+        }
+    }
+
     impl Data {
         /// Create new data.
         /// * `def` - definition.
-        /// * `boxed_value` - data value.
-        pub fn new(def: Definition, boxed_value: Box<dyn Any>) -> Result<Data> {
+        /// * `value` - data value. Its type has to be the same as definition type.
+        ///   Enumeration member can be given by its name.
+        pub fn new(def: Definition, value: impl Into<Value>) -> Result<Data> {
             let children: Vec<Data> = Vec::new();
-            match def.get_type() {
-                Type::Int => match &(*boxed_value).downcast_ref::<i32>() {
-                    Some(value) => Ok({
-                        Data{
-                            def: def,
-                            value: value.to_le_bytes().to_vec(),
-                            children: children,
-                    }}),
-                    None => { panic!("Cannot convert to float"); },
-                },
-                Type::Float => match &(*boxed_value).downcast_ref::<f32>() {
-                    Some(value) => Ok({
-                        Data{
-                            def: def,
-                            value:  value.to_le_bytes().to_vec(),
-                            children:children,
-                    }}),
-                    None => { panic!("Cannot convert to float"); },
-                },
-                Type::String => match &(*boxed_value).downcast_ref::<String>() {
-                    Some(value) => Ok({
-                        Data{
-                            def: def,
-                            value: value.as_bytes().to_vec(),
-                            children: children,
-                    }}),
-                    None => { panic!("Cannot convert to float"); },
-                },
+            let value = def.encode_value(value.into())?;
+            Ok(Data {
+                def,
+                value,
+                blob: None,
+                is_null: false,
+                children,
+            })
+        }
+
+        /// Create new data which value is null.
+        /// Return error if the definition is not nullable.
+        /// * `def` - definition.
+        pub fn null(def: Definition) -> Result<Data> {
+            if !def.is_nullable {
+                return Err(FractalError::NotNullable(def.tag));
+            }
+            Ok(Data {
+                def,
+                value: Vec::new(),
+                blob: None,
+                is_null: true,
+                children: Vec::new(),
+            })
+        }
+
+        /// Create new data from text.  
+        /// Decimal is parsed as "-12.34" and UUID is parsed as "67e55044-10b1-426f-9247-bb680e5fe0c8".
+        /// * `def` - definition. Its type has to be decimal or UUID.
+        /// * `text` - value text.
+        pub fn parse(def: Definition, text: &str) -> Result<Data> {
+            let value = match def.data_type {
+                Type::Decimal => Decimal::parse(text).map(Value::Decimal),
+                Type::Uuid => Uuid::parse(text).map(Value::Uuid),
+                _ => return Err(FractalError::TypeMismatch { tag: def.tag, expected: def.data_type }),
+            };
+            match value {
+                Some(value) => Data::new(def, value),
+                None => Err(FractalError::InvalidText { tag: def.tag, text: text.to_string() }),
             }
         }
 
@@ -64,9 +101,25 @@ pub mod data {
         pub fn read_binary(def: Definition, value: Vec<u8>) -> Data {
             let children: Vec<Data> = Vec::new();
             Data {
-                def: def,
-                value: value,
-                children: children,
+                def,
+                value,
+                blob: None,
+                is_null: false,
+                children,
+            }
+        }
+
+        /// Create new data whose value is stored in blob file. It use when read binary data.  
+        /// The content is not read until it is requested.
+        /// * `def` - Data definition
+        /// * `blob` - blob which has the value.
+        pub fn read_blob(def: Definition, blob: Blob) -> Data {
+            Data {
+                def,
+                value: Vec::new(),
+                blob: Some(blob),
+                is_null: false,
+                children: Vec::new(),
             }
         }
 
@@ -75,20 +128,117 @@ pub mod data {
             &self.def
         }
 
-        /// get data value
-        pub fn get_value(&self) -> Result<Box<dyn Any>> {
-            match self.get_def().get_type() {
-                Type::Int => Ok(Box::new(i32::from_le_bytes(self.value[0..4].try_into()?))),
-                Type::Float => Ok(Box::new(f32::from_le_bytes(self.value[0..4].try_into()?))),
-                Type::String => Ok(Box::new(String::from_utf8(self.value.clone())?)),
+        /// get data value.
+        /// Return None if the value is null.
+        pub fn get_value(&self) -> Result<Option<Value>> {
+            if self.is_null {
+                return Ok(None);
+            }
+            match &self.blob {
+                Some(blob) => Ok(Some(Value::Bytes(blob.read_all()?))),
+                None => Ok(Some(self.def.decode_value(&self.value)?)),
+            }
+        }
+
+        /// Return true if the value is null.
+        pub fn is_null(&self) -> bool {
+            self.is_null
+        }
+
+        /// open binary payload for streaming read.  
+        /// The value stored in blob file is read from the file.
+        pub fn open_bytes(&self) -> Result<Box<dyn Read>> {
+            if self.def.data_type != Type::Bytes {
+                return Err(FractalError::TypeMismatch { tag: self.def.tag, expected: Type::Bytes });
+            }
+            if self.is_null {
+                return Err(FractalError::NullValue(self.def.tag));
+            }
+            match &self.blob {
+                Some(blob) => Ok(Box::new(blob.open()?)),
+                None => Ok(Box::new(Cursor::new(self.value.clone()))),
+            }
+        }
+
+        /// get decimal or UUID value as text.  
+        /// Decimal is formatted in the scale of definition.
+        pub fn format(&self) -> Result<String> {
+            match self.get_value()? {
+                Some(Value::Decimal(decimal)) => Ok(decimal.to_string()),
+                Some(Value::Uuid(uuid)) => Ok(uuid.to_string()),
+                Some(_) => Err(FractalError::TypeMismatch { tag: self.def.tag, expected: self.def.data_type }),
+                None => Err(FractalError::NullValue(self.def.tag)),
             }
         }
 
+        /// get blob which has the value.
+        /// Return None if the value is not stored in blob file.
+        pub fn get_blob(&self) -> Option<&Blob> {
+            self.blob.as_ref()
+        }
+
+        /// get data value as `T`.
+        /// Return error if the value is null or cannot convert to `T`.
+        pub fn get<T: FromValue>(&self) -> Result<T> {
+            let value = match self.get_value()? {
+                Some(value) => value,
+                None => return Err(FractalError::NullValue(self.def.tag)),
+            };
+            match T::from_value(value) {
+                Some(value) => Ok(value),
+                None => Err(FractalError::TypeMismatch { tag: self.def.tag, expected: self.def.data_type }),
+            }
+        }
+
+        /// get element type and its byte size of array.
+        fn element_type(&self) -> Result<(Type, usize)> {
+            match self.def.data_type.element_type() {
+                Some(element_type) => Ok((element_type, element_type.size().unwrap_or(1))),
+                None => Err(FractalError::TypeMismatch { tag: self.def.tag, expected: self.def.data_type }),
+            }
+        }
+
+        /// get number of elements of array.
+        pub fn get_element_len(&self) -> Result<usize> {
+            let (_, size) = self.element_type()?;
+            Ok(self.value.len() / size)
+        }
+
+        /// get element of array.
+        /// Return error if the index is out of the array.
+        /// * `index` - element index.
+        pub fn get_element(&self, index: usize) -> Result<Value> {
+            let (element_type, size) = self.element_type()?;
+            let len = self.value.len() / size;
+            if index >= len {
+                return Err(FractalError::IndexOutOfRange { tag: self.def.tag, index, len });
+            }
+            Value::from_bytes(&element_type, &self.value[index * size..(index + 1) * size])
+        }
+
+        /// push element at the end of array.
+        /// * `value` - element value. Its type has to be the same as element type of definition.
+        pub fn push_value(&mut self, value: impl Into<Value>) -> Result<()> {
+            let (element_type, _) = self.element_type()?;
+            let value: Value = value.into();
+            if value.get_type() != element_type {
+                return Err(FractalError::TypeMismatch { tag: self.def.tag, expected: element_type });
+            }
+            let len = if self.is_null { 1 } else { self.get_element_len()? + 1 };
+            if let Some(max) = self.def.constraints.max_len.filter(|max| len > *max as usize) {
+                return Err(FractalError::TooLong { tag: self.def.tag, len, max });
+            }
+            self.value.append(&mut value.to_bytes());
+            // Null array becomes an array which has the element.
+            self.is_null = false;
+            Ok(())
+        }
+
         /// get child tag data
         /// * `tags` - tag and child data pairs.
         pub fn get_child(&self, root: &DataRoot) -> Option<&Data>{
             if root.is_empty() {
-                Some(&self)
+                Some(self)
             }
             else {
                 match &root[0].1 {
@@ -118,6 +268,36 @@ pub mod data {
             }
         }
 
+        /// get child by index.
+        /// * `index` - data index.
+        pub fn get_child_by_index(&self, index: &[usize]) -> Option<&Data> {
+            if index.is_empty() {
+                Some(self)
+            }
+            else {
+                self.children.get(index[0])?.get_child_by_index(&index[1..])
+            }
+        }
+
+        /// Convert data index to data root.  
+        /// Each data is selected by its first child.
+        /// Return None if the root points to other data (a sibling of the data has the same first child or no child).
+        /// * `index` - data index.
+        pub fn index_to_root(&self, index: &[usize]) -> Option<Vec<(u32, Option<Data>)>> {
+            let mut root = Vec::new();
+            let mut data = self;
+            for i in index {
+                data = data.children.get(*i)?;
+                root.push((data.def.tag, data.children.first().cloned()));
+            }
+            if std::ptr::eq(self.get_child(&root)?, data) {
+                Some(root)
+            }
+            else {
+                None
+            }
+        }
+
         /// get child by index.
         /// * `index` - data index.
         pub fn get_child_mut_by_index(&mut self, index: &[usize]) -> Option<&mut Data> {
@@ -125,7 +305,7 @@ pub mod data {
                 Some(self)
             }
             else {
-                self.children[index[0]].get_child_mut_by_index(&index[1..])
+                self.children.get_mut(index[0])?.get_child_mut_by_index(&index[1..])
             }
         }
 
@@ -134,15 +314,118 @@ pub mod data {
         /// * `data` - Compared data.
         pub fn is_equal_child(&self, data: &Data) -> bool {
             match self.children.iter().find(|e| e.def.tag==data.def.tag) {
-                Some(child) => data.value == child.value,
+                Some(child) => data.has_same_value(child),
                 None => false,
             }
         }
 
+        /// Return true if this value and data value is same.  
+        /// Values in blob files are compared by content hash and length.
+        /// * `data` - Compared data.
+        fn has_same_value(&self, data: &Data) -> bool {
+            if self.is_null || data.is_null {
+                return self.is_null == data.is_null;
+            }
+            // Values of user defined type are compared by the codec.
+            if let Some(codec) = self.def.codec.get().or(data.def.codec.get()) {
+                return codec.compare(&self.value, &data.value) == Some(Ordering::Equal);
+            }
+            // Decimals are compared by value if the scales are different.
+            if self.def.data_type == Type::Decimal && self.def.scale != data.def.scale {
+                return match (self.get_value(), data.get_value()) {
+                    (Ok(Some(a)), Ok(Some(b))) => a == b,
+                    _ => false,
+                };
+            }
+            match (&self.blob, &data.blob) {
+                (None, None) => self.value == data.value,
+                _ => self.content_key() == data.content_key(),
+            }
+        }
+
+        /// get content hash and length of value.
+        fn content_key(&self) -> (u64, u64) {
+            match &self.blob {
+                Some(blob) => (blob.hash, blob.len),
+                None => (content_hash(&self.value), self.value.len() as u64),
+            }
+        }
+
         /// Add child data in this.
+        /// * `root` - Parent data root.
         /// * `data` - Input data for child.
-        pub fn add_child(&mut self, root: &mut DataRoot, data: Data) {
-            self.get_child_mut(root).unwrap().children.push(data);
+        pub fn add_child(&mut self, root: &DataRoot, data: Data) -> Result<()> {
+            match self.get_child_mut(root) {
+                Some(parent) => {
+                    parent.children.push(data);
+                    Ok(())
+                },
+                None => Err(FractalError::DataNotFound),
+            }
+        }
+
+        /// find all descendant data which have the tag (depth-first order).
+        /// * `tag` - data tag.
+        pub fn find_by_tag(&self, tag: u32) -> Vec<&Data> {
+            let mut vec = Vec::new();
+            for child in &self.children {
+                if child.def.tag == tag {
+                    vec.push(child);
+                }
+                vec.append(&mut child.find_by_tag(tag));
+            }
+            vec
+        }
+
+        /// find data index of all descendant data which have the tag (depth-first order).
+        /// * `tag` - data tag.
+        pub fn find_index_by_tag(&self, tag: u32) -> Vec<Vec<usize>> {
+            let mut vec = Vec::new();
+            for (i, child) in self.children.iter().enumerate() {
+                if child.def.tag == tag {
+                    vec.push(vec![i]);
+                }
+                for mut index in child.find_index_by_tag(tag) {
+                    index.insert(0, i);
+                    vec.push(index);
+                }
+            }
+            vec
+        }
+
+        /// remove child by index.
+        /// Return None if the index does not point to any child.
+        /// * `index` - data index. It must not be empty.
+        pub fn remove_child_by_index(&mut self, index: &[usize]) -> Option<Data> {
+            let (last, parent) = index.split_last()?;
+            let parent = self.get_child_mut_by_index(parent)?;
+            if *last < parent.children.len() {
+                Some(parent.children.remove(*last))
+            }
+            else {
+                None
+            }
+        }
+
+        /// set value. Children are not changed.
+        /// * `value` - data value. Its type has to be the same as definition type.
+        pub fn set_value(&mut self, value: impl Into<Value>) -> Result<()> {
+            self.value = self.def.encode_value(value.into())?;
+            self.blob = None;
+            self.is_null = false;
+            Ok(())
+        }
+
+        /// replace definitions of this and all descendant data.  
+        /// The data whose tag is not in the definitions is not changed.
+        /// * `defs` - new definitions by the current tag of data.
+        pub fn replace_defs(&mut self, defs: &HashMap<u32, Definition>) {
+            if let Some(def) = defs.get(&self.def.tag) {
+                self.def = def.clone();
+            }
+            for child in &mut self.children {
+                child.replace_defs(defs);
+            }
         }
 
         /// get children
@@ -150,6 +433,11 @@ pub mod data {
             &self.children
         }
 
+        /// remove all children.
+        pub fn clear_children(&mut self) {
+            self.children = Vec::new();
+        }
+
         /// get value (type is binary)
         pub fn get_bite_value(&self) -> &Vec<u8> {
             &self.value
@@ -165,27 +453,157 @@ pub mod data {
     #[cfg(test)]
     speculate! {
 
-        use std::{thread, time};
-        use rstest::rstest;
-
         describe "data" {
             it "int data r/w" {
-                let def = Definition::new(0xaaaa_bbbb, format!("Int test"), Type::Int, false);
-                let data = Data::new(def, Box::new(3_i32)).unwrap();
-                assert_eq!(data.get_value().unwrap().downcast_ref::<i32>().unwrap(), &3_i32);
+                let def = Definition::new(0xaaaa_bbbb, "Int test".to_string(), Type::Int, false);
+                let data = Data::new(def, 3_i32).unwrap();
+                assert_eq!(data.get_value().unwrap().unwrap().as_i32(), Some(3_i32));
+                assert_eq!(data.get::<i32>().unwrap(), 3_i32);
             }
 
             it "float data r/w" {
-                let def = Definition::new(0xaaaa_bbbb, format!("Float test"), Type::Float, false);
-                let data = Data::new(def, Box::new(4.5_f32)).unwrap();
-                assert_eq!(data.get_value().unwrap().downcast_ref::<f32>().unwrap(), &4.5_f32);
+                let def = Definition::new(0xaaaa_bbbb, "Float test".to_string(), Type::Float, false);
+                let data = Data::new(def, 4.5_f32).unwrap();
+                assert_eq!(data.get_value().unwrap().unwrap().as_f32(), Some(4.5_f32));
+                assert_eq!(data.get::<f32>().unwrap(), 4.5_f32);
             }
 
             it "string data r/w" {
-                let def = Definition::new(0xaaaa_bbbb, format!("String test"), Type::String, false);
-                let data = Data::new(def, Box::new(format!("taro"))).unwrap();
-                assert_eq!(data.get_value().unwrap().downcast_ref::<String>().unwrap(), &format!("taro"));
+                let def = Definition::new(0xaaaa_bbbb, "String test".to_string(), Type::String, false);
+                let data = Data::new(def, "taro").unwrap();
+                assert_eq!(data.get_value().unwrap().unwrap().as_str(), Some("taro"));
+                assert_eq!(data.get::<String>().unwrap(), format!("taro"));
+            }
+
+            it "primitive data r/w" {
+                let values = [
+                    Value::Bool(true), Value::I64(-1), Value::U32(u32::MAX), Value::U64(u64::MAX),
+                    Value::F64(0.1), Value::Byte(0xff),
+                ];
+                for value in values {
+                    let def = Definition::new(0xaaaa_bbbb, "Primitive test".to_string(), value.get_type(), false);
+                    let data = Data::new(def, value.clone()).unwrap();
+                    assert_eq!(data.get_value().unwrap(), Some(value));
+                }
+                let def = Definition::new(0xaaaa_bbbb, "I64 test".to_string(), Type::I64, false);
+                assert_eq!(Data::new(def, 3_i64).unwrap().get::<i64>().unwrap(), 3_i64);
+            }
+
+            it "type mismatch" {
+                let def = Definition::new(0xaaaa_bbbb, "Int test".to_string(), Type::Int, false);
+                match Data::new(def.clone(), "taro".to_string()) {
+                    Err(FractalError::TypeMismatch { tag, .. }) => assert_eq!(tag, 0xaaaa_bbbb),
+                    _ => panic!("type mismatch is not detected"),
+                }
+                let data = Data::new(def, 3).unwrap();
+                match data.get::<String>() {
+                    Err(FractalError::TypeMismatch { tag, .. }) => assert_eq!(tag, 0xaaaa_bbbb),
+                    _ => panic!("type mismatch is not detected"),
+                }
+                assert!(data.open_bytes().is_err());
+            }
+
+            it "enum data r/w" {
+                let mut def = Definition::new(0xaaaa_bbbb, "Enum test".to_string(), Type::Enum, false);
+                def.set_members(vec![format!("active"), format!("closed")]).unwrap();
+                let data = Data::new(def.clone(), "closed").unwrap();
+                assert_eq!(data.get_bite_value(), &vec![0x01, 0x00]);
+                assert_eq!(data.get_value().unwrap(), Some(Value::Enum("closed".to_string())));
+                assert_eq!(data.get::<String>().unwrap(), format!("closed"));
+                match Data::new(def, "open") {
+                    Err(FractalError::UnknownMember { tag, .. }) => assert_eq!(tag, 0xaaaa_bbbb),
+                    _ => panic!("unknown member is not detected"),
+                }
+            }
+
+            it "array data r/w" {
+                let def = Definition::new(0xaaaa_bbbb, "Int array test".to_string(), Type::IntArray, false);
+                let mut data = Data::new(def, vec![1_i32, 2]).unwrap();
+                data.push_value(3).unwrap();
+                assert_eq!(data.get_element_len().unwrap(), 3);
+                assert_eq!(data.get_value().unwrap().unwrap().as_i32_slice(), Some(&[1_i32, 2, 3][..]));
+                assert_eq!(data.get_element(2).unwrap(), Value::Int(3));
+                match data.get_element(3) {
+                    Err(FractalError::IndexOutOfRange { index, len, .. }) => assert_eq!((index, len), (3, 3)),
+                    _ => panic!("out of range is not detected"),
+                }
+                match data.push_value(4.5_f32) {
+                    Err(FractalError::TypeMismatch { expected, .. }) => assert_eq!(expected, Type::Int),
+                    _ => panic!("type mismatch is not detected"),
+                }
+                let def = Definition::new(0xaaaa_bbbb, "Float array test".to_string(), Type::FloatArray, false);
+                let data = Data::new(def, vec![0.5_f32, 1.5]).unwrap();
+                assert_eq!(data.get::<Vec<f32>>().unwrap(), vec![0.5_f32, 1.5]);
+            }
+
+            it "null data" {
+                let mut def = Definition::new(0xaaaa_bbbb, "Int test".to_string(), Type::Int, false);
+                match Data::null(def.clone()) {
+                    Err(FractalError::NotNullable(tag)) => assert_eq!(tag, 0xaaaa_bbbb),
+                    _ => panic!("not nullable is not detected"),
+                }
+                def.set_nullable(true);
+                let data = Data::null(def.clone()).unwrap();
+                assert!(data.is_null());
+                assert_eq!(data.get_value().unwrap(), None);
+                match data.get::<i32>() {
+                    Err(FractalError::NullValue(tag)) => assert_eq!(tag, 0xaaaa_bbbb),
+                    _ => panic!("null value is not detected"),
+                }
+                assert_ne!(data, Data::new(def, 0).unwrap());
+            }
+
+            it "decimal data r/w" {
+                let mut def = Definition::new(0xaaaa_bbbb, "Decimal test".to_string(), Type::Decimal, false);
+                def.scale = 2;
+                let data = Data::parse(def.clone(), "-1.5").unwrap();
+                assert_eq!(data.get_bite_value(), &(-150_i128).to_le_bytes().to_vec());
+                assert_eq!(data.format().unwrap(), format!("-1.50"));
+                assert_eq!(data.get::<Decimal>().unwrap(), Decimal::parse("-1.5").unwrap());
+                match Data::parse(def.clone(), "0.125") {
+                    Err(FractalError::ScaleMismatch { scale, .. }) => assert_eq!(scale, 2),
+                    _ => panic!("scale mismatch is not detected"),
+                }
+                match Data::parse(def.clone(), "1,5") {
+                    Err(FractalError::InvalidText { text, .. }) => assert_eq!(text, format!("1,5")),
+                    _ => panic!("invalid text is not detected"),
+                }
+                // Exact equality. 0.1 + 0.2 is 0.3.
+                let mut parent = Data::new(Definition::new(0x0001_0001, "Parent".to_string(), Type::Int, false), 0).unwrap();
+                let sum = Decimal::new(Decimal::parse("0.1").unwrap().get_mantissa() + 2, 1).unwrap();
+                parent.add_child(&[], Data::new(def.clone(), sum).unwrap()).unwrap();
+                assert!(parent.is_equal_child(&Data::parse(def.clone(), "0.30").unwrap()));
+                assert!(!parent.is_equal_child(&Data::parse(def.clone(), "0.31").unwrap()));
+                let mut other = def;
+                other.scale = 3;
+                assert!(parent.is_equal_child(&Data::parse(other, "0.300").unwrap()));
+            }
+
+            it "uuid data r/w" {
+                let def = Definition::new(0xaaaa_bbbb, "UUID test".to_string(), Type::Uuid, false);
+                let data = Data::parse(def.clone(), "67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+                assert_eq!(data.get_bite_value().len(), 16);
+                assert_eq!(data.format().unwrap(), format!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+                let mut parent = Data::new(Definition::new(0x0001_0001, "Parent".to_string(), Type::Int, false), 0).unwrap();
+                parent.add_child(&[], data).unwrap();
+                assert!(parent.is_equal_child(&Data::parse(def.clone(), "67e5504410b1426f9247bb680e5fe0c8").unwrap()));
+                assert!(Data::parse(def, "67e55044").is_err());
+                let def = Definition::new(0xaaaa_bbbb, "Int test".to_string(), Type::Int, false);
+                match Data::parse(def, "1") {
+                    Err(FractalError::TypeMismatch { expected, .. }) => assert_eq!(expected, Type::Int),
+                    _ => panic!("type mismatch is not detected"),
+                }
+            }
+
+            it "bytes data r/w" {
+                let def = Definition::new(0xaaaa_bbbb, "Bytes test".to_string(), Type::Bytes, false);
+                let data = Data::new(def, vec![0x02_u8, 0x03]).unwrap();
+                assert_eq!(data.get::<Vec<u8>>().unwrap(), vec![0x02_u8, 0x03]);
+                let mut vec = Vec::new();
+                data.open_bytes().unwrap().read_to_end(&mut vec).unwrap();
+                assert_eq!(vec, vec![0x02_u8, 0x03]);
+                assert_eq!(data.get_blob(), None);
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,61 @@
+#[allow(clippy::module_inception)]
+pub mod blob {
+
+    use crate::manager::error::error::{FractalError, Result};
+
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::PathBuf;
+
+    /// Binary payload which is stored out of line (next to the data file).
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Blob {
+        /// content hash (xxHash64).
+        pub hash: u64,
+        /// byte length of content.
+        pub len: u64,
+        /// blob file path.
+        pub path: PathBuf,
+    }
+
+    impl Blob {
+        /// Create new.
+        /// * `hash` - content hash.
+        /// * `len` - byte length of content.
+        /// * `path` - blob file path.
+        pub fn new(hash: u64, len: u64, path: PathBuf) -> Blob {
+            Blob {
+                hash,
+                len,
+                path,
+            }
+        }
+
+        /// Open blob file for streaming read.
+        pub fn open(&self) -> Result<File> {
+            Ok(File::open(&self.path)?)
+        }
+
+        /// Read all of content and verify length and hash.
+        pub fn read_all(&self) -> Result<Vec<u8>> {
+            let mut vec = Vec::new();
+            self.open()?.read_to_end(&mut vec)?;
+            if vec.len() as u64 != self.len || content_hash(&vec) != self.hash {
+                return Err(FractalError::BlobMismatch(self.hash));
+            }
+            Ok(vec)
+        }
+
+        /// get blob file name.
+        /// * `hash` - content hash.
+        pub fn file_name(hash: u64) -> String {
+            format!("{:016x}.blob", hash)
+        }
+    }
+
+    /// Calculate content hash of binary payload.
+    /// * `data` - binary payload.
+    pub fn content_hash(data: &[u8]) -> u64 {
+        xxhash_rust::xxh64::xxh64(data, 0)
+    }
+}
@@ -0,0 +1,67 @@
+#[allow(clippy::module_inception)]
+pub mod codec {
+
+    use crate::manager::data::value::value::Value;
+    use crate::manager::error::error::Result;
+
+    use std::cmp::Ordering;
+    use std::fmt;
+    use std::sync::Arc;
+
+    /// Codec of user defined value type (`Type::Custom`).
+    /// It is registered on `Manager` under the type code.
+    pub trait ValueCodec: Send + Sync {
+        /// name of the type. It is used in messages.
+        fn name(&self) -> &str;
+
+        /// Convert value to binary.
+        /// * `value` - value. The codec decides which value is accepted.
+        fn encode(&self, value: &Value) -> Result<Vec<u8>>;
+
+        /// Convert binary to value.
+        /// * `bytes` - binary value.
+        fn decode(&self, bytes: &[u8]) -> Result<Value>;
+
+        /// Compare binary values. It is used by range queries and by matching data keys.
+        /// Binaries are compared in lexicographic order by default.
+        fn compare(&self, a: &[u8], b: &[u8]) -> Option<Ordering> {
+            a.partial_cmp(b)
+        }
+    }
+
+    /// Registered codec which is attached to definition.
+    /// It is not a part of definition, so it is not compared, written and serialized.
+    #[derive(Clone, Default)]
+    pub struct Codec(Option<Arc<dyn ValueCodec>>);
+
+    impl Codec {
+        /// Create new.
+        /// * `codec` - registered codec.
+        pub fn new(codec: Arc<dyn ValueCodec>) -> Codec {
+            Codec(Some(codec))
+        }
+
+        /// get codec.
+        /// Return None if the codec is not registered.
+        pub fn get(&self) -> Option<&dyn ValueCodec> {
+            self.0.as_deref()
+        }
+    }
+
+    impl PartialEq for Codec {
+        fn eq(&self, _: &Codec) -> bool {
+            true
+        }
+    }
+
+    impl Eq for Codec {}
+
+    impl fmt::Debug for Codec {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self.get() {
+                Some(codec) => write!(f, "Codec({})", codec.name()),
+                None => write!(f, "Codec(None)"),
+            }
+        }
+    }
+}
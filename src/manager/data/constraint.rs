@@ -0,0 +1,296 @@
+#[allow(clippy::module_inception)]
+pub mod constraint {
+
+    use crate::manager::data::value::value::Value;
+    use crate::manager::error::error::{FractalError, Result};
+
+    use regex::Regex;
+    use serde::{Serialize, Deserialize};
+    use std::cmp::Ordering;
+
+    /// Bound of numeric value.
+    /// Integer bound is compared exactly with integer values, so it can be any i64 or u64 value.
+    #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+    pub enum Number {
+        /// integer bound.
+        Int(i128),
+        /// floating point bound.
+        Float(f64),
+    }
+
+    impl Number {
+        /// Compare numbers. Integers are compared exactly with floating point numbers.
+        /// Return None if either is NaN.
+        /// * `other` - compared number.
+        pub fn compare(&self, other: &Number) -> Option<Ordering> {
+            match (self, other) {
+                (Number::Int(a), Number::Int(b)) => Some(a.cmp(b)),
+                (Number::Float(a), Number::Float(b)) => a.partial_cmp(b),
+                (Number::Int(a), Number::Float(b)) => compare_int_float(*a, *b),
+                (Number::Float(a), Number::Int(b)) => compare_int_float(*b, *a).map(Ordering::reverse),
+            }
+        }
+    }
+
+    /// Numbers are equal if they have the same type and the same value (bits of floating point number).
+    impl PartialEq for Number {
+        fn eq(&self, other: &Number) -> bool {
+            match (self, other) {
+                (Number::Int(a), Number::Int(b)) => a == b,
+                (Number::Float(a), Number::Float(b)) => a.to_bits() == b.to_bits(),
+                _ => false,
+            }
+        }
+    }
+
+    impl Eq for Number {}
+
+    impl From<i32> for Number {
+        fn from(value: i32) -> Number {
+            Number::Int(i128::from(value))
+        }
+    }
+
+    impl From<i64> for Number {
+        fn from(value: i64) -> Number {
+            Number::Int(i128::from(value))
+        }
+    }
+
+    impl From<u64> for Number {
+        fn from(value: u64) -> Number {
+            Number::Int(i128::from(value))
+        }
+    }
+
+    impl From<f64> for Number {
+        fn from(value: f64) -> Number {
+            Number::Float(value)
+        }
+    }
+
+    /// Value constraints of definition.
+    /// Constraints which do not apply to the value type are ignored.
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    pub struct Constraints {
+        /// min value of numeric types.
+        pub min: Option<Number>,
+        /// max value of numeric types.
+        pub max: Option<Number>,
+        /// max length of string (characters), binary payload (bytes) and array (elements).
+        pub max_len: Option<u32>,
+        /// string, binary payload and array are not empty.
+        pub not_empty: bool,
+        /// regular expression which the whole string matches.
+        pattern: Option<String>,
+        /// compiled pattern.
+        #[serde(skip)]
+        regex: Option<Regex>,
+    }
+
+    impl Constraints {
+        /// Return true if no constraint is set.
+        pub fn is_empty(&self) -> bool {
+            self.min.is_none() && self.max.is_none() && self.max_len.is_none() && !self.not_empty && self.pattern.is_none()
+        }
+
+        /// get regular expression of string.
+        pub fn get_pattern(&self) -> Option<&str> {
+            self.pattern.as_deref()
+        }
+
+        /// set regular expression of string.
+        /// Return error if the pattern is not regular expression.
+        /// * `pattern` - regular expression which the whole string matches. None removes the constraint.
+        pub fn set_pattern(&mut self, pattern: Option<String>) -> Result<()> {
+            self.regex = match &pattern {
+                Some(pattern) => Some(compile(pattern)?),
+                None => None,
+            };
+            self.pattern = pattern;
+            Ok(())
+        }
+
+        /// get constraints which inherit the base constraints.  
+        /// The constraint which is set in this overrides the base one. Not empty cannot be overridden.
+        /// * `base` - constraints of base definition.
+        pub fn inherit(&self, base: &Constraints) -> Constraints {
+            let (pattern, regex) = match &self.pattern {
+                Some(_) => (self.pattern.clone(), self.regex.clone()),
+                None => (base.pattern.clone(), base.regex.clone()),
+            };
+            Constraints {
+                min: self.min.or(base.min),
+                max: self.max.or(base.max),
+                max_len: self.max_len.or(base.max_len),
+                not_empty: self.not_empty || base.not_empty,
+                pattern,
+                regex,
+            }
+        }
+
+        /// Check that the value satisfies constraints.
+        /// * `tag` - tag of the value. It is used in errors.
+        /// * `value` - checked value.
+        pub fn check(&self, tag: u32, value: &Value) -> Result<()> {
+            if let Some(number) = as_number(value) {
+                // NaN is out of any range.
+                if self.min.is_some_and(|min| number.compare(&min).is_none_or(Ordering::is_lt))
+                    || self.max.is_some_and(|max| number.compare(&max).is_none_or(Ordering::is_gt)) {
+                    return Err(FractalError::ValueOutOfRange(tag));
+                }
+            }
+            if let Some(len) = get_len(value) {
+                if self.not_empty && len == 0 {
+                    return Err(FractalError::EmptyValue(tag));
+                }
+                if let Some(max) = self.max_len.filter(|max| len > *max as usize) {
+                    return Err(FractalError::TooLong { tag, len, max });
+                }
+            }
+            if let (Some(pattern), Value::String(text)) = (&self.pattern, value) {
+                // The compiled pattern is lost when constraints are deserialized.
+                let is_match = match &self.regex {
+                    Some(regex) => regex.is_match(text),
+                    None => compile(pattern)?.is_match(text),
+                };
+                if !is_match {
+                    return Err(FractalError::PatternMismatch(tag));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl PartialEq for Constraints {
+        fn eq(&self, other: &Constraints) -> bool {
+            self.min == other.min
+                && self.max == other.max
+                && self.max_len == other.max_len
+                && self.not_empty == other.not_empty
+                && self.pattern == other.pattern
+        }
+    }
+
+    impl Eq for Constraints {}
+
+    /// compile pattern which matches the whole string.
+    /// * `pattern` - regular expression.
+    fn compile(pattern: &str) -> Result<Regex> {
+        match Regex::new(&format!("^(?:{})$", pattern)) {
+            Ok(regex) => Ok(regex),
+            Err(_) => Err(FractalError::InvalidPattern(pattern.to_string())),
+        }
+    }
+
+    /// get numeric value. Integer values are not converted to floating point number.
+    fn as_number(value: &Value) -> Option<Number> {
+        match value {
+            Value::Int(value) => Some(Number::Int(i128::from(*value))),
+            Value::Float(value) => Some(Number::Float(f64::from(*value))),
+            Value::I64(value) => Some(Number::Int(i128::from(*value))),
+            Value::U32(value) => Some(Number::Int(i128::from(*value))),
+            Value::U64(value) => Some(Number::Int(i128::from(*value))),
+            Value::F64(value) => Some(Number::Float(*value)),
+            Value::Byte(value) => Some(Number::Int(i128::from(*value))),
+            _ => None,
+        }
+    }
+
+    /// Compare integer and floating point number exactly.
+    /// Return None if the floating point number is NaN.
+    /// * `int` - integer.
+    /// * `float` - floating point number.
+    fn compare_int_float(int: i128, float: f64) -> Option<Ordering> {
+        // 2^127 is the first floating point number out of i128.
+        const LIMIT: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+        if float.is_nan() {
+            return None;
+        }
+        if float >= LIMIT {
+            return Some(Ordering::Less);
+        }
+        if float < -LIMIT {
+            return Some(Ordering::Greater);
+        }
+        // The integer part is exact, so only the fraction decides equal integers.
+        let whole = float.trunc();
+        match int.cmp(&(whole as i128)) {
+            Ordering::Equal => 0.0.partial_cmp(&(float - whole)),
+            ordering => Some(ordering),
+        }
+    }
+
+    /// get length of string (characters), binary payload (bytes) and array (elements).
+    fn get_len(value: &Value) -> Option<usize> {
+        match value {
+            Value::String(value) => Some(value.chars().count()),
+            Value::Bytes(value) => Some(value.len()),
+            Value::IntArray(value) => Some(value.len()),
+            Value::FloatArray(value) => Some(value.len()),
+            Value::I64Array(value) => Some(value.len()),
+            Value::U32Array(value) => Some(value.len()),
+            Value::U64Array(value) => Some(value.len()),
+            Value::F64Array(value) => Some(value.len()),
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "constraint" {
+            it "check" {
+                let mut constraints = Constraints {
+                    min: Some(Number::Float(0.0)), max: Some(Number::Float(10.0)), ..Constraints::default()
+                };
+                assert!(constraints.check(1, &Value::Int(10)).is_ok());
+                assert!(matches!(constraints.check(1, &Value::Float(-0.5)), Err(FractalError::ValueOutOfRange(1))));
+                assert!(matches!(constraints.check(1, &Value::F64(f64::NAN)), Err(FractalError::ValueOutOfRange(1))));
+                // The range does not apply to string.
+                assert!(constraints.check(1, &Value::from("-1")).is_ok());
+
+                constraints.max_len = Some(3);
+                constraints.not_empty = true;
+                constraints.set_pattern(Some("[a-z]+".to_string())).unwrap();
+                assert!(constraints.check(1, &Value::from("abc")).is_ok());
+                assert!(matches!(constraints.check(1, &Value::from("")), Err(FractalError::EmptyValue(1))));
+                assert!(matches!(constraints.check(1, &Value::from("abcd")), Err(FractalError::TooLong { len: 4, .. })));
+                assert!(matches!(constraints.check(1, &Value::from("ab1")), Err(FractalError::PatternMismatch(1))));
+                assert!(matches!(constraints.check(1, &Value::from(vec![1_u32, 2, 3, 4])), Err(FractalError::TooLong { .. })));
+                assert!(matches!(constraints.set_pattern(Some("(".to_string())), Err(FractalError::InvalidPattern(_))));
+            }
+
+            it "exact integer bound" {
+                // 2^53 + 1 is the first integer which f64 cannot express.
+                let mut constraints = Constraints { max: Some(Number::from(1_u64 << 53)), ..Constraints::default() };
+                assert!(constraints.check(1, &Value::U64(1 << 53)).is_ok());
+                assert!(matches!(constraints.check(1, &Value::U64((1 << 53) + 1)), Err(FractalError::ValueOutOfRange(1))));
+                assert!(matches!(constraints.check(1, &Value::I64((1 << 53) + 1)), Err(FractalError::ValueOutOfRange(1))));
+                constraints.max = Some(Number::from(i64::MAX));
+                assert!(constraints.check(1, &Value::I64(i64::MAX)).is_ok());
+                assert!(matches!(constraints.check(1, &Value::U64(i64::MAX as u64 + 1)), Err(FractalError::ValueOutOfRange(1))));
+                constraints.min = Some(Number::from(u64::MAX));
+                constraints.max = None;
+                assert!(constraints.check(1, &Value::U64(u64::MAX)).is_ok());
+                assert!(constraints.check(1, &Value::U64(u64::MAX - 1)).is_err());
+
+                // Integers are compared exactly with floating point bound.
+                constraints.min = Some(Number::Float(9_007_199_254_740_992.0));
+                assert!(matches!(constraints.check(1, &Value::U64((1 << 53) - 1)), Err(FractalError::ValueOutOfRange(1))));
+                assert!(constraints.check(1, &Value::U64((1 << 53) + 1)).is_ok());
+                constraints.min = Some(Number::Float(0.5));
+                assert!(constraints.check(1, &Value::Int(0)).is_err());
+                assert!(constraints.check(1, &Value::Int(1)).is_ok());
+                constraints.min = Some(Number::Int(1));
+                assert!(constraints.check(1, &Value::F64(0.5)).is_err());
+                assert!(constraints.check(1, &Value::F64(f64::NAN)).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+#[allow(clippy::module_inception)]
+pub mod decimal {
+
+    use std::cmp::Ordering;
+    use std::fmt;
+
+    /// Max number of digits after the decimal point.
+    pub const MAX_SCALE: u8 = 28;
+
+    /// Fixed-point decimal. Its value is `mantissa / 10^scale`.
+    /// Decimals which have the same value are equal even if their scales are different.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Decimal {
+        mantissa: i128,
+        scale: u8,
+    }
+
+    impl Decimal {
+        /// Create decimal.
+        /// Return None if the scale is larger than `MAX_SCALE`.
+        /// * `mantissa` - mantissa.
+        /// * `scale` - number of digits after the decimal point.
+        pub fn new(mantissa: i128, scale: u8) -> Option<Decimal> {
+            if scale <= MAX_SCALE {
+                Some(Decimal { mantissa, scale })
+            }
+            else {
+                None
+            }
+        }
+
+        /// Parse decimal such as "-12.34".
+        /// Return None if the text is not decimal or it is too large.
+        /// * `text` - decimal text.
+        pub fn parse(text: &str) -> Option<Decimal> {
+            let (negative, digits) = match text.strip_prefix('-') {
+                Some(digits) => (true, digits),
+                None => (false, text.strip_prefix('+').unwrap_or(text)),
+            };
+            let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+            if integer.is_empty() || !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let scale = u8::try_from(fraction.len()).ok()?;
+            let mut mantissa: i128 = 0;
+            for b in integer.bytes().chain(fraction.bytes()) {
+                mantissa = mantissa.checked_mul(10)?.checked_add(i128::from(b - b'0'))?;
+            }
+            Decimal::new(if negative { -mantissa } else { mantissa }, scale)
+        }
+
+        /// get mantissa.
+        pub fn get_mantissa(&self) -> i128 {
+            self.mantissa
+        }
+
+        /// get number of digits after the decimal point.
+        pub fn get_scale(&self) -> u8 {
+            self.scale
+        }
+
+        /// Convert decimal to the scale without rounding.
+        /// Return None if the value cannot be represented in the scale.
+        /// * `scale` - number of digits after the decimal point.
+        pub fn rescale(&self, scale: u8) -> Option<Decimal> {
+            let mantissa = if scale >= self.scale {
+                self.mantissa.checked_mul(10_i128.checked_pow(u32::from(scale - self.scale))?)?
+            }
+            else {
+                let divisor = 10_i128.pow(u32::from(self.scale - scale));
+                if self.mantissa % divisor != 0 {
+                    return None;
+                }
+                self.mantissa / divisor
+            };
+            Decimal::new(mantissa, scale)
+        }
+
+        /// get integer part (floor) and fraction part in `10^MAX_SCALE` units.
+        fn split(&self) -> (i128, i128) {
+            let divisor = 10_i128.pow(u32::from(self.scale));
+            let fraction = self.mantissa.rem_euclid(divisor) * 10_i128.pow(u32::from(MAX_SCALE - self.scale));
+            (self.mantissa.div_euclid(divisor), fraction)
+        }
+    }
+
+    impl PartialEq for Decimal {
+        fn eq(&self, other: &Decimal) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Decimal {}
+
+    impl PartialOrd for Decimal {
+        fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Decimal {
+        fn cmp(&self, other: &Decimal) -> Ordering {
+            self.split().cmp(&other.split())
+        }
+    }
+
+    impl fmt::Display for Decimal {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let digits = self.mantissa.unsigned_abs().to_string();
+            let scale = usize::from(self.scale);
+            let digits = format!("{:0>width$}", digits, width = scale + 1);
+            let (integer, fraction) = digits.split_at(digits.len() - scale);
+            let sign = if self.mantissa < 0 { "-" } else { "" };
+            if fraction.is_empty() {
+                write!(f, "{}{}", sign, integer)
+            }
+            else {
+                write!(f, "{}{}.{}", sign, integer, fraction)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "decimal" {
+            it "parse and format" {
+                let decimal = Decimal::parse("-12.340").unwrap();
+                assert_eq!((decimal.get_mantissa(), decimal.get_scale()), (-12_340, 3));
+                assert_eq!(decimal.to_string(), format!("-12.340"));
+                assert_eq!(Decimal::parse("0.05").unwrap().to_string(), format!("0.05"));
+                assert_eq!(Decimal::parse("7").unwrap().to_string(), format!("7"));
+                assert_eq!(Decimal::parse("1.2.3"), None);
+                assert_eq!(Decimal::parse(".5"), None);
+                assert_eq!(Decimal::parse("99999999999999999999999999999999999999999"), None);
+            }
+
+            it "exact compare" {
+                assert_eq!(Decimal::parse("1.50").unwrap(), Decimal::parse("1.5").unwrap());
+                assert!(Decimal::parse("-0.01").unwrap() < Decimal::parse("0").unwrap());
+                assert!(Decimal::parse("0.1").unwrap() < Decimal::parse("0.11").unwrap());
+                assert_eq!(Decimal::parse("1.25").unwrap().rescale(1), None);
+                assert_eq!(Decimal::parse("1.2").unwrap().rescale(2).unwrap().get_mantissa(), 120);
+            }
+        }
+    }
+}
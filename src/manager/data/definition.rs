@@ -1,29 +1,210 @@
 
+#[allow(clippy::module_inception)]
 pub mod definition {
 
+    use crate::manager::data::value::value::Value;
+    use crate::manager::data::codec::codec::Codec;
+    use crate::manager::data::decimal::decimal::Decimal;
+    use crate::manager::data::constraint::constraint::Constraints;
+    use crate::manager::error::error::{FractalError, Result};
+
     use serde::{Serialize, Deserialize};
 
     /// type in data value
-    #[derive (Clone, Copy, Debug, Serialize, Deserialize)]
+    #[derive (Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
     pub enum Type {
+        /// i32
         Int,
+        /// f32
         Float,
         String,
+        Bool,
+        I64,
+        U32,
+        U64,
+        F64,
+        /// u8
+        Byte,
+        /// Days from 1970-01-01.
+        Date,
+        /// Nanoseconds from midnight.
+        Time,
+        /// Nanoseconds from 1970-01-01T00:00:00Z and UTC offset.
+        DateTime,
+        Duration,
+        /// Binary payload.
+        Bytes,
+        /// Member of enumeration. It is stored as member code (u16).
+        Enum,
+        /// Pointer to other data node.
+        Reference,
+        /// packed array of i32.
+        IntArray,
+        /// packed array of f32.
+        FloatArray,
+        /// packed array of i64.
+        I64Array,
+        /// packed array of u32.
+        U32Array,
+        /// packed array of u64.
+        U64Array,
+        /// packed array of f64.
+        F64Array,
+        /// f32 vector which dimension is declared in definition.
+        Vector,
+        /// Latitude and longitude (f64).
+        Point,
+        /// South west and north east points.
+        BoundingBox,
+        /// Fixed-point decimal (i128 mantissa) which scale is declared in definition.
+        Decimal,
+        /// 128 bit universally unique identifier.
+        Uuid,
+        /// User defined type (0x80 to 0xfe). Its value is converted by the codec registered on `Manager`.
+        Custom(u8),
     }
 
     impl Type {
-        pub fn u8_to_type(buf: u8) -> Type {
+        /// Convert type code to type.
+        /// * `buf` - type code.
+        pub fn u8_to_type(buf: u8) -> Result<Type> {
             match buf {
-                0x00 => Type::Int,
-                0x01 => Type::Float,
-                0x02 => Type::String,
-                _ => panic!("convert error"),
+                0x00 => Ok(Type::Int),
+                0x01 => Ok(Type::Float),
+                0x02 => Ok(Type::String),
+                0x03 => Ok(Type::Bool),
+                0x04 => Ok(Type::I64),
+                0x05 => Ok(Type::U32),
+                0x06 => Ok(Type::U64),
+                0x07 => Ok(Type::F64),
+                0x08 => Ok(Type::Byte),
+                0x09 => Ok(Type::Date),
+                0x0a => Ok(Type::Time),
+                0x0b => Ok(Type::DateTime),
+                0x0c => Ok(Type::Duration),
+                0x0d => Ok(Type::Bytes),
+                0x0e => Ok(Type::Enum),
+                0x0f => Ok(Type::Reference),
+                0x10 => Ok(Type::IntArray),
+                0x11 => Ok(Type::FloatArray),
+                0x12 => Ok(Type::I64Array),
+                0x13 => Ok(Type::U32Array),
+                0x14 => Ok(Type::U64Array),
+                0x15 => Ok(Type::F64Array),
+                0x16 => Ok(Type::Vector),
+                0x17 => Ok(Type::Point),
+                0x18 => Ok(Type::BoundingBox),
+                0x19 => Ok(Type::Decimal),
+                0x1a => Ok(Type::Uuid),
+                0x80..=0xfe => Ok(Type::Custom(buf)),
+                _ => Err(FractalError::UnknownTypeCode(buf)),
+            }
+        }
+
+        /// get type code.
+        pub fn get_type_num(&self) -> u8 {
+            match self {
+                Type::Int => 0x00,
+                Type::Float => 0x01,
+                Type::String => 0x02,
+                Type::Bool => 0x03,
+                Type::I64 => 0x04,
+                Type::U32 => 0x05,
+                Type::U64 => 0x06,
+                Type::F64 => 0x07,
+                Type::Byte => 0x08,
+                Type::Date => 0x09,
+                Type::Time => 0x0a,
+                Type::DateTime => 0x0b,
+                Type::Duration => 0x0c,
+                Type::Bytes => 0x0d,
+                Type::Enum => 0x0e,
+                Type::Reference => 0x0f,
+                Type::IntArray => 0x10,
+                Type::FloatArray => 0x11,
+                Type::I64Array => 0x12,
+                Type::U32Array => 0x13,
+                Type::U64Array => 0x14,
+                Type::F64Array => 0x15,
+                Type::Vector => 0x16,
+                Type::Point => 0x17,
+                Type::BoundingBox => 0x18,
+                Type::Decimal => 0x19,
+                Type::Uuid => 0x1a,
+                Type::Custom(code) => *code,
             }
         }
+
+        /// get byte size of value.  
+        /// Return None if the size is not fixed.
+        pub fn size(&self) -> Option<usize> {
+            match self {
+                Type::Int | Type::Float | Type::U32 | Type::Date => Some(4),
+                Type::I64 | Type::U64 | Type::F64 | Type::Time => Some(8),
+                // i64 and i32, u64 and u32.
+                Type::DateTime | Type::Duration => Some(12),
+                Type::Point | Type::Decimal | Type::Uuid => Some(16),
+                Type::BoundingBox => Some(32),
+                Type::Enum => Some(2),
+                Type::Bool | Type::Byte => Some(1),
+                // The size of vector is declared in definition.
+                // The codec may be changed, so the size of user defined type is not fixed.
+                Type::String | Type::Bytes | Type::Reference | Type::Vector | Type::Custom(_) => None,
+                // Arrays have any number of elements.
+                Type::IntArray | Type::FloatArray | Type::I64Array | Type::U32Array | Type::U64Array | Type::F64Array => None,
+            }
+        }
+
+        /// get element type of array.  
+        /// Return None if the type is not array.
+        pub fn element_type(&self) -> Option<Type> {
+            match self {
+                Type::IntArray => Some(Type::Int),
+                Type::FloatArray => Some(Type::Float),
+                Type::I64Array => Some(Type::I64),
+                Type::U32Array => Some(Type::U32),
+                Type::U64Array => Some(Type::U64),
+                Type::F64Array => Some(Type::F64),
+                _ => None,
+            }
+        }
+    }
+
+    /// Child tag of definition and its cardinality.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct ChildDef {
+        /// child tag.
+        pub tag: u32,
+        /// min number of the child data.
+        pub min: u32,
+        /// max number of the child data. None is unbounded.
+        pub max: Option<u32>,
+    }
+
+    impl ChildDef {
+        /// Create child definition which has any number of data.
+        /// * `tag` - child tag.
+        pub fn new(tag: u32) -> ChildDef {
+            ChildDef { tag, min: 0, max: None }
+        }
+
+        /// Create child definition which has limited number of data.
+        /// * `tag` - child tag.
+        /// * `min` - min number of the child data.
+        /// * `max` - max number of the child data. None is unbounded.
+        pub fn with_range(tag: u32, min: u32, max: Option<u32>) -> ChildDef {
+            ChildDef { tag, min, max }
+        }
+
+        /// Return true if the number of the child data is in the range.
+        /// * `count` - number of the child data.
+        pub fn contains(&self, count: usize) -> bool {
+            count >= self.min as usize && self.max.is_none_or(|max| count <= max as usize)
+        }
     }
 
     /// Data definition
-    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
     pub struct Definition {
         /// tag value.  
         /// The first 4 digits : the group number  
@@ -37,24 +218,56 @@ pub mod definition {
         pub explanation: String,
         /// is multiple.
         pub is_multiple: bool,
+        /// is able to have null value.
+        pub is_nullable: bool,
 
+        /// is able to be extended by other definitions.
         pub is_base: bool,
-        /// tag number of children
-        pub children: Vec<u32>,
+        /// tag of base definition which this definition extends.
+        pub base: Option<u32>,
+        /// is able to have itself as descendant.
+        pub is_recursive: bool,
+        /// max number of the data of this tag on the way from top level data. None is unbounded.
+        pub max_depth: Option<u32>,
+        /// child tags and their cardinality.
+        pub children: Vec<ChildDef>,
+        /// member names of enumeration. The index is member code.
+        pub members: Vec<String>,
+        /// dimension of vector.
+        pub dimension: u32,
+        /// number of digits after the decimal point of decimal.
+        pub scale: u8,
+        /// value constraints.
+        pub constraints: Constraints,
+        /// binary of default value. It is set by `set_default`.
+        pub default_value: Option<Vec<u8>>,
+        /// codec of user defined type.
+        #[serde(skip)]
+        pub codec: Codec,
     }
 
     impl Definition {
         // Create new definition
         pub fn new(tag: u32, name: String, data_type: Type, is_multiple: bool) -> Definition {
-            let vec: Vec<u32> = Vec::new();
+            let vec: Vec<ChildDef> = Vec::new();
             Definition {
-                tag: tag,
-                name: name,
-                data_type: data_type,
-                explanation: format!(""),
-                is_multiple: is_multiple,
+                tag,
+                name,
+                data_type,
+                explanation: String::new(),
+                is_multiple,
+                is_nullable: false,
                 is_base: false,
+                base: None,
+                is_recursive: false,
+                max_depth: None,
                 children: vec,
+                members: Vec::new(),
+                dimension: 0,
+                scale: 0,
+                constraints: Constraints::default(),
+                default_value: None,
+                codec: Codec::default(),
             }
         }
 
@@ -92,11 +305,7 @@ pub mod definition {
 
         /// get type by binary.
         pub fn get_type_num(&self) -> u8 {
-            match self.data_type {
-                Type::Int => 0x00,
-                Type::Float => 0x01,
-                Type::String => 0x02,
-            }
+            self.data_type.get_type_num()
         }
 
         /// get explanation about definition.
@@ -113,6 +322,166 @@ pub mod definition {
         pub fn is_base(&self) -> bool {
             self.is_base
         }
+
+        /// set whether this definition is able to be extended by other definitions.
+        /// * `is_base` - is able to be extended.
+        pub fn set_is_base(&mut self, is_base: bool) {
+            self.is_base = is_base
+        }
+
+        /// set whether this definition is able to have itself as descendant.
+        /// * `is_recursive` - is able to have itself as descendant.
+        /// * `max_depth` - max number of the data of this tag on the way from top level data. None is unbounded.
+        pub fn set_recursive(&mut self, is_recursive: bool, max_depth: Option<u32>) {
+            self.is_recursive = is_recursive;
+            self.max_depth = max_depth;
+        }
+
+        /// get definition which inherits the base definition.  
+        /// Children, constraints and explanation are inherited, and the ones set in this override them.
+        /// * `base` - effective base definition.
+        pub fn inherit(&self, base: &Definition) -> Definition {
+            let mut def = self.clone();
+            if def.explanation.is_empty() {
+                def.explanation = base.explanation.clone();
+            }
+            def.constraints = self.constraints.inherit(&base.constraints);
+            def.children = base.children.clone();
+            for child in &self.children {
+                match def.children.iter_mut().find(|e| e.tag == child.tag) {
+                    Some(e) => *e = *child,
+                    None => def.children.push(*child),
+                }
+            }
+            def
+        }
+
+        /// get child definition.
+        /// Return None if the tag is not a child of this definition.
+        /// * `tag` - child tag.
+        pub fn get_child(&self, tag: u32) -> Option<&ChildDef> {
+            self.children.iter().find(|child| child.tag == tag)
+        }
+
+        /// get child tags.
+        pub fn get_child_tags(&self) -> Vec<u32> {
+            self.children.iter().map(|child| child.tag).collect()
+        }
+
+        /// set whether data is able to have null value.
+        /// * `is_nullable` - is able to have null value.
+        pub fn set_nullable(&mut self, is_nullable: bool) {
+            self.is_nullable = is_nullable
+        }
+
+        /// get member names of enumeration.
+        pub fn get_members(&self) -> &Vec<String> {
+            &self.members
+        }
+
+        /// set member names of enumeration.
+        /// Return error if the names are duplicated or too many.
+        /// * `members` - member names. The index is member code.
+        pub fn set_members(&mut self, members: Vec<String>) -> Result<()> {
+            let is_duplicated = members.iter().enumerate().any(|(i, name)| members[..i].contains(name));
+            if is_duplicated || members.len() > usize::from(u16::MAX) + 1 {
+                return Err(FractalError::InvalidMembers(self.tag));
+            }
+            self.members = members;
+            Ok(())
+        }
+
+        /// get default value.
+        /// Return None if the definition has no default value.
+        pub fn get_default(&self) -> Result<Option<Value>> {
+            self.default_value.as_ref().map(|bytes| self.decode_value(bytes)).transpose()
+        }
+
+        /// set default value which is used by `Manager::instantiate`.
+        /// Return error if the value does not match type or constraints.
+        /// * `value` - default value.
+        pub fn set_default(&mut self, value: impl Into<Value>) -> Result<()> {
+            self.default_value = Some(self.encode_value(value.into())?);
+            Ok(())
+        }
+
+        /// remove default value.
+        pub fn clear_default(&mut self) {
+            self.default_value = None;
+        }
+
+        /// get byte size of value.  
+        /// Return None if the size is not fixed.
+        pub fn value_size(&self) -> Option<usize> {
+            match self.data_type {
+                Type::Vector => Some(self.dimension as usize * 4),
+                data_type => data_type.size(),
+            }
+        }
+
+        /// Convert value to binary which is stored in data.  
+        /// Enumeration member is converted to member code.
+        /// Return error if the value does not satisfy constraints.
+        /// * `value` - data value. Its type has to be the same as definition type.
+        pub fn encode_value(&self, value: Value) -> Result<Vec<u8>> {
+            self.constraints.check(self.tag, &value)?;
+            match (self.data_type, value) {
+                // Member name is also accepted as string.
+                (Type::Enum, Value::Enum(name)) | (Type::Enum, Value::String(name)) => {
+                    match self.members.iter().position(|member| *member == name) {
+                        Some(code) => Ok((code as u16).to_le_bytes().to_vec()),
+                        None => Err(FractalError::UnknownMember { tag: self.tag, name }),
+                    }
+                },
+                // f32 array is also accepted as vector.
+                (Type::Vector, Value::Vector(vector)) | (Type::Vector, Value::FloatArray(vector)) => {
+                    if vector.len() != self.dimension as usize {
+                        return Err(FractalError::DimensionMismatch {
+                            tag: self.tag, expected: self.dimension as usize, actual: vector.len()
+                        });
+                    }
+                    Ok(Value::Vector(vector).to_bytes())
+                },
+                // Decimal is stored in the declared scale. It is not rounded.
+                (Type::Decimal, Value::Decimal(decimal)) => match decimal.rescale(self.scale) {
+                    Some(decimal) => Ok(Value::Decimal(decimal).to_bytes()),
+                    None => Err(FractalError::ScaleMismatch { tag: self.tag, scale: self.scale }),
+                },
+                (Type::Custom(code), value) => match self.codec.get() {
+                    Some(codec) => codec.encode(&value),
+                    None => Err(FractalError::UnregisteredCodec(code)),
+                },
+                (data_type, value) if value.get_type() == data_type => Ok(value.to_bytes()),
+                _ => Err(FractalError::TypeMismatch { tag: self.tag, expected: self.data_type }),
+            }
+        }
+
+        /// Convert binary which is stored in data to value.
+        /// * `bytes` - binary value.
+        pub fn decode_value(&self, bytes: &[u8]) -> Result<Value> {
+            match self.data_type {
+                Type::Enum => {
+                    let code = match bytes.try_into() {
+                        Ok(code) => usize::from(u16::from_le_bytes(code)),
+                        Err(_) => return Err(FractalError::InvalidValue(Type::Enum)),
+                    };
+                    match self.members.get(code) {
+                        Some(name) => Ok(Value::Enum(name.clone())),
+                        None => Err(FractalError::InvalidValue(Type::Enum)),
+                    }
+                },
+                Type::Decimal => match Value::from_bytes(&Type::Decimal, bytes)?.as_decimal()
+                    .and_then(|decimal| Decimal::new(decimal.get_mantissa(), self.scale)) {
+                    Some(decimal) => Ok(Value::Decimal(decimal)),
+                    None => Err(FractalError::InvalidValue(Type::Decimal)),
+                },
+                Type::Custom(code) => match self.codec.get() {
+                    Some(codec) => codec.decode(bytes),
+                    None => Err(FractalError::UnregisteredCodec(code)),
+                },
+                data_type => Value::from_bytes(&data_type, bytes),
+            }
+        }
     }
 
     #[cfg(test)]
@@ -127,19 +496,68 @@ pub mod definition {
     speculate! {
         describe "definition" {
             it "def name" {
-                let def = Definition::new(0xaaaa_bbbb, format!("Int test"), Type::Int, false);
-                assert_eq!(def.get_name(), &format!("Int test"));
+                let def = Definition::new(0xaaaa_bbbb, "Int test".to_string(), Type::Int, false);
+                assert_eq!(def.get_name(), &"Int test".to_string());
             }
 
             it "def is base" {
-                let def = Definition::new(0xaaaa_bbbb, format!("Int test"), Type::Int, false);
-                assert_eq!(def.is_base(), false);
+                let def = Definition::new(0xaaaa_bbbb, "Int test".to_string(), Type::Int, false);
+                assert!(!def.is_base());
             }
 
             it "explanation" {
-                let mut def = Definition::new(0xaaaa_bbbb, format!("Int test"), Type::Int, false);
-                def.set_explanation(format!("exp test"));
-                assert_eq!(def.get_explanation(), &format!("exp test"));
+                let mut def = Definition::new(0xaaaa_bbbb, "Int test".to_string(), Type::Int, false);
+                def.set_explanation("exp test".to_string());
+                assert_eq!(def.get_explanation(), &"exp test".to_string());
+            }
+
+            it "type code" {
+                for code in 0x00..=0x1a {
+                    assert_eq!(Type::u8_to_type(code).unwrap().get_type_num(), code);
+                }
+            }
+
+            it "enumeration" {
+                let mut def = Definition::new(0xaaaa_bbbb, "Enum test".to_string(), Type::Enum, false);
+                def.set_members(vec![format!("active"), format!("closed")]).unwrap();
+                let bytes = def.encode_value(Value::from("closed")).unwrap();
+                assert_eq!(bytes, vec![0x01, 0x00]);
+                assert_eq!(def.decode_value(&bytes).unwrap(), Value::Enum("closed".to_string()));
+                match def.encode_value(Value::from("open")) {
+                    Err(FractalError::UnknownMember { name, .. }) => assert_eq!(name, format!("open")),
+                    _ => panic!("unknown member is not detected"),
+                }
+                assert!(def.decode_value(&[0x02, 0x00]).is_err());
+                assert!(def.set_members(vec![format!("active"), format!("active")]).is_err());
+            }
+
+            it "vector" {
+                let mut def = Definition::new(0xaaaa_bbbb, "Vector test".to_string(), Type::Vector, false);
+                def.dimension = 2;
+                assert_eq!(def.value_size(), Some(8));
+                let bytes = def.encode_value(Value::from(vec![0.5_f32, 1.5])).unwrap();
+                assert_eq!(def.decode_value(&bytes).unwrap(), Value::Vector(vec![0.5, 1.5]));
+                match def.encode_value(Value::Vector(vec![0.5])) {
+                    Err(FractalError::DimensionMismatch { expected, actual, .. }) => assert_eq!((expected, actual), (2, 1)),
+                    _ => panic!("dimension mismatch is not detected"),
+                }
+            }
+
+            it "custom type code" {
+                assert_eq!(Type::u8_to_type(0x80).unwrap(), Type::Custom(0x80));
+                assert_eq!(Type::Custom(0xfe).get_type_num(), 0xfe);
+                let def = Definition::new(0xaaaa_bbbb, "Custom test".to_string(), Type::Custom(0x80), false);
+                match def.encode_value(Value::from("1.2.3")) {
+                    Err(FractalError::UnregisteredCodec(code)) => assert_eq!(code, 0x80),
+                    _ => panic!("unregistered codec is not detected"),
+                }
+            }
+
+            it "unknown type code" {
+                match Type::u8_to_type(0xff) {
+                    Err(FractalError::UnknownTypeCode(code)) => assert_eq!(code, 0xff),
+                    _ => panic!("unknown type code is not detected"),
+                }
             }
         }
     }
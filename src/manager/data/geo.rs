@@ -0,0 +1,302 @@
+#[allow(clippy::module_inception)]
+pub mod geo {
+
+    /// Mean earth radius (meters).
+    pub const EARTH_RADIUS: f64 = 6_371_008.8;
+    /// Max number of entries in a node of R-tree.
+    const NODE_CAPACITY: usize = 16;
+
+    /// Geographic point.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Point {
+        lat: f64,
+        lon: f64,
+    }
+
+    impl Point {
+        /// Create point.
+        /// Return None if latitude or longitude is out of range.
+        /// * `lat` - latitude (-90 to 90 degrees).
+        /// * `lon` - longitude (-180 to 180 degrees).
+        pub fn new(lat: f64, lon: f64) -> Option<Point> {
+            if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
+                Some(Point { lat, lon })
+            }
+            else {
+                None
+            }
+        }
+
+        /// get latitude (degrees).
+        pub fn get_lat(&self) -> f64 {
+            self.lat
+        }
+
+        /// get longitude (degrees).
+        pub fn get_lon(&self) -> f64 {
+            self.lon
+        }
+
+        /// get great-circle distance (meters) by haversine formula.
+        /// * `other` - other point.
+        pub fn distance(&self, other: &Point) -> f64 {
+            let d_lat = (other.lat - self.lat).to_radians();
+            let d_lon = (other.lon - self.lon).to_radians();
+            let a = (d_lat / 2.0).sin().powi(2)
+                + self.lat.to_radians().cos() * other.lat.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+            2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+        }
+    }
+
+    /// Geographic bounding box.
+    /// If west is larger than east, the box crosses the antimeridian.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct BoundingBox {
+        south_west: Point,
+        north_east: Point,
+    }
+
+    impl BoundingBox {
+        /// Create bounding box.
+        /// Return None if south is larger than north.
+        /// * `south_west` - south west corner.
+        /// * `north_east` - north east corner.
+        pub fn new(south_west: Point, north_east: Point) -> Option<BoundingBox> {
+            if south_west.lat <= north_east.lat {
+                Some(BoundingBox { south_west, north_east })
+            }
+            else {
+                None
+            }
+        }
+
+        /// Create bounding box which contains the circle.
+        /// * `center` - center of circle.
+        /// * `radius` - radius of circle (meters).
+        pub fn around(center: &Point, radius: f64) -> BoundingBox {
+            let d_lat = (radius / EARTH_RADIUS).to_degrees();
+            let south = (center.lat - d_lat).max(-90.0);
+            let north = (center.lat + d_lat).min(90.0);
+            // The circle contains a pole, or it is too large.
+            let cos = south.to_radians().cos().min(north.to_radians().cos());
+            let d_lon = if cos <= 0.0 { 180.0 } else { (d_lat / cos).min(180.0) };
+            let (west, east) = if d_lon >= 180.0 {
+                (-180.0, 180.0)
+            }
+            else {
+                (wrap_lon(center.lon - d_lon), wrap_lon(center.lon + d_lon))
+            };
+            BoundingBox {
+                south_west: Point { lat: south, lon: west },
+                north_east: Point { lat: north, lon: east },
+            }
+        }
+
+        /// get south west corner.
+        pub fn get_south_west(&self) -> Point {
+            self.south_west
+        }
+
+        /// get north east corner.
+        pub fn get_north_east(&self) -> Point {
+            self.north_east
+        }
+
+        /// Return true if the point is in this box.
+        /// * `point` - point.
+        pub fn contains(&self, point: &Point) -> bool {
+            self.split().iter().any(|r| r.contains(point))
+        }
+
+        /// split box at the antimeridian.
+        fn split(&self) -> Vec<Rect> {
+            let (south, north) = (self.south_west.lat, self.north_east.lat);
+            let (west, east) = (self.south_west.lon, self.north_east.lon);
+            if west <= east {
+                vec![Rect { south, west, north, east }]
+            }
+            else {
+                vec![
+                    Rect { south, west, north, east: 180.0 },
+                    Rect { south, west: -180.0, north, east },
+                ]
+            }
+        }
+    }
+
+    /// wrap longitude in -180 to 180 degrees.
+    fn wrap_lon(lon: f64) -> f64 {
+        (lon + 180.0).rem_euclid(360.0) - 180.0
+    }
+
+    /// Rectangle which does not cross the antimeridian.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Rect {
+        south: f64,
+        west: f64,
+        north: f64,
+        east: f64,
+    }
+
+    impl Rect {
+        fn of_point(point: &Point) -> Rect {
+            Rect { south: point.lat, west: point.lon, north: point.lat, east: point.lon }
+        }
+
+        fn union(&self, other: &Rect) -> Rect {
+            Rect {
+                south: self.south.min(other.south),
+                west: self.west.min(other.west),
+                north: self.north.max(other.north),
+                east: self.east.max(other.east),
+            }
+        }
+
+        fn contains(&self, point: &Point) -> bool {
+            self.south <= point.lat && point.lat <= self.north && self.west <= point.lon && point.lon <= self.east
+        }
+
+        fn intersects(&self, other: &Rect) -> bool {
+            self.south <= other.north && other.south <= self.north && self.west <= other.east && other.west <= self.east
+        }
+    }
+
+    /// Node of R-tree.
+    #[derive(Clone, Debug)]
+    enum Node {
+        Leaf(Vec<(Vec<usize>, Point)>),
+        Branch(Vec<(Rect, Node)>),
+    }
+
+    /// R-tree of points. It is built at once by sort-tile-recursive packing.
+    #[derive(Clone, Debug)]
+    pub struct RTree {
+        root: Option<(Rect, Node)>,
+    }
+
+    impl RTree {
+        /// Build R-tree.
+        /// * `entries` - index path from the parent data and point.
+        pub fn build(entries: Vec<(Vec<usize>, Point)>) -> RTree {
+            if entries.is_empty() {
+                return RTree { root: None };
+            }
+            let mut nodes: Vec<(Rect, Node)> = tile(entries, |e| e.1)
+                .into_iter()
+                .map(|leaf| {
+                    let rect = leaf.iter().skip(1)
+                        .fold(Rect::of_point(&leaf[0].1), |rect, e| rect.union(&Rect::of_point(&e.1)));
+                    (rect, Node::Leaf(leaf))
+                })
+                .collect();
+            while nodes.len() > 1 {
+                nodes = tile(nodes, |e| Point { lat: (e.0.south + e.0.north) / 2.0, lon: (e.0.west + e.0.east) / 2.0 })
+                    .into_iter()
+                    .map(|branch| {
+                        let rect = branch.iter().skip(1).fold(branch[0].0, |rect, e| rect.union(&e.0));
+                        (rect, Node::Branch(branch))
+                    })
+                    .collect();
+            }
+            RTree { root: nodes.pop() }
+        }
+
+        /// find index paths of points in the box.
+        /// * `area` - bounding box.
+        pub fn search(&self, area: &BoundingBox) -> Vec<Vec<usize>> {
+            let mut found = Vec::new();
+            if let Some(root) = &self.root {
+                for rect in area.split() {
+                    search_node(root, &rect, &mut found);
+                }
+            }
+            found
+        }
+    }
+
+    /// group entries in tiles of node capacity.
+    /// Entries are sorted by latitude, sliced, and each slice is sorted by longitude.
+    fn tile<T>(mut entries: Vec<T>, center: impl Fn(&T) -> Point) -> Vec<Vec<T>> {
+        let node_len = entries.len().div_ceil(NODE_CAPACITY);
+        let slice_len = (node_len as f64).sqrt().ceil() as usize * NODE_CAPACITY;
+        entries.sort_by(|a, b| center(a).lat.total_cmp(&center(b).lat));
+        let mut tiles = Vec::new();
+        while !entries.is_empty() {
+            let rest = entries.split_off(slice_len.min(entries.len()));
+            let mut slice = std::mem::replace(&mut entries, rest);
+            slice.sort_by(|a, b| center(a).lon.total_cmp(&center(b).lon));
+            while !slice.is_empty() {
+                let rest = slice.split_off(NODE_CAPACITY.min(slice.len()));
+                tiles.push(std::mem::replace(&mut slice, rest));
+            }
+        }
+        tiles
+    }
+
+    /// search points in the rectangle.
+    fn search_node(node: &(Rect, Node), rect: &Rect, found: &mut Vec<Vec<usize>>) {
+        if !node.0.intersects(rect) {
+            return;
+        }
+        match &node.1 {
+            Node::Leaf(entries) => {
+                for (index, point) in entries {
+                    if rect.contains(point) {
+                        found.push(index.clone());
+                    }
+                }
+            },
+            Node::Branch(children) => {
+                for child in children {
+                    search_node(child, rect, found);
+                }
+            },
+        }
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "geo" {
+            it "point" {
+                assert_eq!(Point::new(90.1, 0.0), None);
+                let tokyo = Point::new(35.6812, 139.7671).unwrap();
+                let osaka = Point::new(34.7025, 135.4959).unwrap();
+                // About 403 km.
+                assert!((tokyo.distance(&osaka) - 403_000.0).abs() < 2_000.0);
+            }
+
+            it "bounding box" {
+                let area = BoundingBox::new(Point::new(-10.0, 170.0).unwrap(), Point::new(10.0, -170.0).unwrap())
+                    .unwrap();
+                assert!(area.contains(&Point::new(0.0, 180.0).unwrap()));
+                assert!(area.contains(&Point::new(0.0, -175.0).unwrap()));
+                assert!(!area.contains(&Point::new(0.0, 0.0).unwrap()));
+                let around = BoundingBox::around(&Point::new(0.0, 179.9).unwrap(), 100_000.0);
+                assert!(around.contains(&Point::new(0.0, -179.5).unwrap()));
+            }
+
+            it "r-tree" {
+                let entries: Vec<(Vec<usize>, Point)> = (0..1000)
+                    .map(|i| (vec![i], Point::new((i / 40) as f64 - 12.0, (i % 40) as f64 * 9.0 - 180.0).unwrap()))
+                    .collect();
+                let tree = RTree::build(entries.clone());
+                let area = BoundingBox::new(Point::new(-1.5, 170.0).unwrap(), Point::new(1.5, -170.0).unwrap())
+                    .unwrap();
+                let mut found = tree.search(&area);
+                found.sort();
+                let expected: Vec<Vec<usize>> = entries.into_iter()
+                    .filter(|e| area.contains(&e.1))
+                    .map(|e| e.0)
+                    .collect();
+                assert_eq!(found, expected);
+                assert_eq!(found.len(), 9);
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+#[allow(clippy::module_inception)]
+pub mod reference {
+
+    use crate::manager::data::data::DataRoot;
+    use crate::manager::data::definition::definition::Type;
+    use crate::manager::error::error::{FractalError, Result};
+
+    use std::fmt;
+
+    /// Step of reference path.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Step {
+        /// data tag.
+        pub tag: u32,
+        /// tag and binary value of the child which selects the data.
+        /// If it is None, the only data of the tag is selected.
+        pub key: Option<(u32, Vec<u8>)>,
+    }
+
+    /// Pointer to other data node.
+    /// It is the path from the parent data, so it does not change when the data is written and read.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Reference {
+        /// data name in the same table. If it is None, the data is in the same data file.
+        pub data_name: Option<String>,
+        /// path from the parent data.
+        pub path: Vec<Step>,
+    }
+
+    impl Reference {
+        /// Create new reference.  
+        /// Return error if a key is null or stored in blob file, because its binary value cannot select the data.
+        /// * `data_name` - data name in the same table. If it is None, the data is in the same data file.
+        /// * `root` - data root of referenced data.
+        pub fn new(data_name: Option<String>, root: &DataRoot) -> Result<Reference> {
+            let mut path = Vec::new();
+            for (tag, data) in root {
+                let key = match data {
+                    Some(data) if data.is_null() || data.get_blob().is_some() =>
+                        return Err(FractalError::InvalidKey(data.get_def().tag)),
+                    Some(data) => Some((data.get_def().tag, data.get_bite_value().clone())),
+                    None => None,
+                };
+                path.push(Step { tag: *tag, key });
+            }
+            Ok(Reference {
+                data_name,
+                path,
+            })
+        }
+
+        /// replace the tag in the path.
+        /// Return true if the path has the tag.
+        /// * `tag` - replaced tag.
+        /// * `new_tag` - new tag.
+        pub fn replace_tag(&mut self, tag: u32, new_tag: u32) -> bool {
+            let mut is_replaced = false;
+            for step in &mut self.path {
+                if step.tag == tag {
+                    step.tag = new_tag;
+                    is_replaced = true;
+                }
+                if let Some((key_tag, _)) = step.key.as_mut().filter(|(key_tag, _)| *key_tag == tag) {
+                    *key_tag = new_tag;
+                    is_replaced = true;
+                }
+            }
+            is_replaced
+        }
+
+        /// Convert reference to binary.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut vec = Vec::new();
+            match &self.data_name {
+                Some(name) => {
+                    vec.push(1);
+                    push_slice(&mut vec, name.as_bytes());
+                },
+                None => vec.push(0),
+            }
+            vec.extend_from_slice(&(self.path.len() as u32).to_le_bytes());
+            for step in &self.path {
+                vec.extend_from_slice(&step.tag.to_le_bytes());
+                match &step.key {
+                    Some((tag, value)) => {
+                        vec.push(1);
+                        vec.extend_from_slice(&tag.to_le_bytes());
+                        push_slice(&mut vec, value);
+                    },
+                    None => vec.push(0),
+                }
+            }
+            vec
+        }
+
+        /// Convert binary to reference.
+        /// * `bytes` - binary value.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Reference> {
+            let mut index = 0;
+            let data_name = match read_bytes(bytes, &mut index, 1)?[0] {
+                0 => None,
+                _ => {
+                    let len = read_u32(bytes, &mut index)? as usize;
+                    Some(String::from_utf8(read_bytes(bytes, &mut index, len)?.to_vec())?)
+                },
+            };
+            let mut path = Vec::new();
+            for _ in 0..read_u32(bytes, &mut index)? {
+                let tag = read_u32(bytes, &mut index)?;
+                let key = match read_bytes(bytes, &mut index, 1)?[0] {
+                    0 => None,
+                    _ => {
+                        let key_tag = read_u32(bytes, &mut index)?;
+                        let len = read_u32(bytes, &mut index)? as usize;
+                        Some((key_tag, read_bytes(bytes, &mut index, len)?.to_vec()))
+                    },
+                };
+                path.push(Step { tag, key });
+            }
+            if index != bytes.len() {
+                return Err(FractalError::InvalidValue(Type::Reference));
+            }
+            Ok(Reference {
+                data_name,
+                path,
+            })
+        }
+    }
+
+    /// Reference is shown as `data name/tag[key tag=key value]/...`.
+    /// `.` means the same data file.
+    impl fmt::Display for Reference {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.data_name.as_deref().unwrap_or("."))?;
+            for step in &self.path {
+                write!(f, "/{:08x}", step.tag)?;
+                if let Some((tag, value)) = &step.key {
+                    write!(f, "[{:08x}=", tag)?;
+                    for byte in value {
+                        write!(f, "{:02x}", byte)?;
+                    }
+                    write!(f, "]")?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// push length (u32) and binary.
+    fn push_slice(vec: &mut Vec<u8>, slice: &[u8]) {
+        vec.extend_from_slice(&(slice.len() as u32).to_le_bytes());
+        vec.extend_from_slice(slice);
+    }
+
+    /// read binary and move index.
+    fn read_bytes<'a>(bytes: &'a [u8], index: &mut usize, len: usize) -> Result<&'a [u8]> {
+        match bytes.get(*index..index.saturating_add(len)) {
+            Some(slice) => {
+                *index += len;
+                Ok(slice)
+            },
+            None => Err(FractalError::Truncated { index: *index, len }),
+        }
+    }
+
+    /// read u32 (little endian) and move index.
+    fn read_u32(bytes: &[u8], index: &mut usize) -> Result<u32> {
+        let mut buf = [0_u8; 4];
+        buf.copy_from_slice(read_bytes(bytes, index, 4)?);
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        use crate::manager::data::data::Data;
+        use crate::manager::data::definition::definition::Definition;
+
+        describe "reference" {
+            it "binary r/w" {
+                let def = Definition::new(0x0001_0002, "Id".to_string(), Type::Int, false);
+                let key = Data::new(def, 3).unwrap();
+                let reference = Reference::new(
+                    Some("customer".to_string()), &[(0x0001_0001, Some(key)), (0x0001_0003, None)]
+                ).unwrap();
+                assert_eq!(reference.to_string(), format!("customer/00010001[00010002=03000000]/00010003"));
+                let bytes = reference.to_bytes();
+                assert_eq!(Reference::from_bytes(&bytes).unwrap(), reference);
+                assert!(Reference::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+            }
+
+            it "keyless data" {
+                let mut def = Definition::new(0x0001_0002, "Id".to_string(), Type::Int, false);
+                def.set_nullable(true);
+                let null = Data::null(def).unwrap();
+                assert!(matches!(Reference::new(None, &[(0x0001_0001, Some(null))]),
+                    Err(FractalError::InvalidKey(0x0001_0002))));
+            }
+        }
+    }
+}
@@ -0,0 +1,222 @@
+#[allow(clippy::module_inception)]
+pub mod temporal {
+
+    /// Nanoseconds in a day.
+    pub const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+    /// Nanoseconds in a second.
+    const NANOS_PER_SECOND: i64 = 1_000_000_000;
+    /// Max UTC offset (seconds).
+    const MAX_OFFSET: i32 = 86_400;
+
+    /// Calendar date. It is stored as days from 1970-01-01.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Date {
+        days: i32,
+    }
+
+    impl Date {
+        /// Create date from days from 1970-01-01.
+        /// * `days` - days from 1970-01-01.
+        pub fn from_days(days: i32) -> Date {
+            Date { days }
+        }
+
+        /// Create date from year, month and day.
+        /// Return None if the date does not exist.
+        /// * `year` - year.
+        /// * `month` - month (1 to 12).
+        /// * `day` - day (1 to 31).
+        pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Date> {
+            if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+                return None;
+            }
+            // Days from civil (proleptic Gregorian calendar).
+            let y = i64::from(year) - i64::from(month <= 2);
+            let era = y.div_euclid(400);
+            let yoe = y - era * 400;
+            let mp = (i64::from(month) + 9) % 12;
+            let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+            let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+            i32::try_from(era * 146_097 + doe - 719_468).ok().map(Date::from_days)
+        }
+
+        /// get days from 1970-01-01.
+        pub fn get_days(&self) -> i32 {
+            self.days
+        }
+
+        /// get year, month and day.
+        pub fn to_ymd(&self) -> (i32, u32, u32) {
+            // Civil from days (proleptic Gregorian calendar).
+            let z = i64::from(self.days) + 719_468;
+            let era = z.div_euclid(146_097);
+            let doe = z - era * 146_097;
+            let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+            let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+            let mp = (5 * doy + 2) / 153;
+            let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+            let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+            let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+            (year, month, day)
+        }
+    }
+
+    /// Time of day. It is stored as nanoseconds from midnight.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Time {
+        nanos: u64,
+    }
+
+    impl Time {
+        /// Create time from nanoseconds from midnight.
+        /// Return None if it is not less than a day.
+        /// * `nanos` - nanoseconds from midnight.
+        pub fn from_nanos(nanos: u64) -> Option<Time> {
+            if nanos < NANOS_PER_DAY {
+                Some(Time { nanos })
+            }
+            else {
+                None
+            }
+        }
+
+        /// Create time from hour, minute, second and nanosecond.
+        /// Return None if the time does not exist.
+        pub fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Time> {
+            if hour >= 24 || minute >= 60 || second >= 60 || nano >= 1_000_000_000 {
+                return None;
+            }
+            let seconds = u64::from(hour) * 3600 + u64::from(minute) * 60 + u64::from(second);
+            Time::from_nanos(seconds * 1_000_000_000 + u64::from(nano))
+        }
+
+        /// get nanoseconds from midnight.
+        pub fn get_nanos(&self) -> u64 {
+            self.nanos
+        }
+
+        /// get hour, minute, second and nanosecond.
+        pub fn to_hms_nano(&self) -> (u32, u32, u32, u32) {
+            let seconds = self.nanos / 1_000_000_000;
+            (
+                (seconds / 3600) as u32,
+                (seconds / 60 % 60) as u32,
+                (seconds % 60) as u32,
+                (self.nanos % 1_000_000_000) as u32,
+            )
+        }
+    }
+
+    /// Date and time with UTC offset.
+    /// It is stored as nanoseconds from 1970-01-01T00:00:00Z and UTC offset (seconds).
+    /// It is ordered by the instant, and then by the offset.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct DateTime {
+        epoch_nanos: i64,
+        offset: i32,
+    }
+
+    impl DateTime {
+        /// Create date time.
+        /// Return None if offset is not within 24 hours.
+        /// * `epoch_nanos` - nanoseconds from 1970-01-01T00:00:00Z.
+        /// * `offset` - UTC offset (seconds).
+        pub fn new(epoch_nanos: i64, offset: i32) -> Option<DateTime> {
+            // i32::MIN has no absolute value in i32.
+            if offset.unsigned_abs() < MAX_OFFSET.unsigned_abs() {
+                Some(DateTime { epoch_nanos, offset })
+            }
+            else {
+                None
+            }
+        }
+
+        /// Create date time from local date and time.
+        /// Return None if it is out of range.
+        /// * `date` - local date.
+        /// * `time` - local time.
+        /// * `offset` - UTC offset (seconds).
+        pub fn from_local(date: Date, time: Time, offset: i32) -> Option<DateTime> {
+            let local = i64::from(date.get_days()).checked_mul(NANOS_PER_DAY as i64)?
+                .checked_add(time.get_nanos() as i64)?;
+            let epoch_nanos = local.checked_sub(i64::from(offset) * NANOS_PER_SECOND)?;
+            DateTime::new(epoch_nanos, offset)
+        }
+
+        /// get nanoseconds from 1970-01-01T00:00:00Z.
+        pub fn get_epoch_nanos(&self) -> i64 {
+            self.epoch_nanos
+        }
+
+        /// get UTC offset (seconds).
+        pub fn get_offset(&self) -> i32 {
+            self.offset
+        }
+
+        /// get local date.
+        pub fn date(&self) -> Date {
+            Date::from_days(self.local_nanos().div_euclid(NANOS_PER_DAY as i128) as i32)
+        }
+
+        /// get local time.
+        pub fn time(&self) -> Time {
+            Time { nanos: self.local_nanos().rem_euclid(NANOS_PER_DAY as i128) as u64 }
+        }
+
+        /// get nanoseconds from 1970-01-01T00:00:00 in local time.
+        fn local_nanos(&self) -> i128 {
+            i128::from(self.epoch_nanos) + i128::from(self.offset) * i128::from(NANOS_PER_SECOND)
+        }
+    }
+
+    /// get days in month.
+    fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "temporal" {
+            it "date" {
+                assert_eq!(Date::from_ymd(1970, 1, 1).unwrap().get_days(), 0);
+                assert_eq!(Date::from_ymd(2000, 3, 1).unwrap().get_days(), 11_017);
+                assert_eq!(Date::from_ymd(1969, 12, 31).unwrap().get_days(), -1);
+                assert_eq!(Date::from_days(19_723).to_ymd(), (2024, 1, 1));
+                assert_eq!(Date::from_ymd(2023, 2, 29), None);
+                assert!(Date::from_ymd(2024, 2, 29).unwrap() < Date::from_ymd(2024, 3, 1).unwrap());
+            }
+
+            it "time" {
+                let time = Time::from_hms_nano(23, 59, 58, 7).unwrap();
+                assert_eq!(time.to_hms_nano(), (23, 59, 58, 7));
+                assert_eq!(Time::from_hms_nano(24, 0, 0, 0), None);
+                assert_eq!(Time::from_nanos(NANOS_PER_DAY), None);
+            }
+
+            it "date time" {
+                let date = Date::from_ymd(2024, 1, 1).unwrap();
+                let time = Time::from_hms_nano(9, 0, 0, 0).unwrap();
+                // 2024-01-01T09:00:00+09:00 is 2024-01-01T00:00:00Z.
+                let jst = DateTime::from_local(date, time, 9 * 3600).unwrap();
+                assert_eq!(jst.get_epoch_nanos(), 19_723 * NANOS_PER_DAY as i64);
+                assert_eq!((jst.date(), jst.time()), (date, time));
+                // Instant is compared before offset.
+                let utc = DateTime::new(jst.get_epoch_nanos() + 1, 0).unwrap();
+                assert!(jst < utc);
+                assert_eq!(DateTime::new(0, -86_400), None);
+                assert_eq!(DateTime::new(0, i32::MIN), None);
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+#[allow(clippy::module_inception)]
+pub mod uuid {
+
+    use std::fmt;
+
+    /// 128 bit universally unique identifier.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Uuid {
+        bytes: [u8; 16],
+    }
+
+    impl Uuid {
+        /// Create UUID from bytes.
+        /// * `bytes` - 16 bytes (big endian).
+        pub fn from_bytes(bytes: [u8; 16]) -> Uuid {
+            Uuid { bytes }
+        }
+
+        /// Parse UUID such as "67e55044-10b1-426f-9247-bb680e5fe0c8".
+        /// Hyphens may be omitted. Return None if the text is not UUID.
+        /// * `text` - UUID text.
+        pub fn parse(text: &str) -> Option<Uuid> {
+            let hex: Vec<u8> = if text.len() == 36 {
+                // Hyphens are at 8, 13, 18 and 23.
+                for (i, b) in text.bytes().enumerate() {
+                    if [8, 13, 18, 23].contains(&i) != (b == b'-') {
+                        return None;
+                    }
+                }
+                text.bytes().filter(|b| *b != b'-').collect()
+            }
+            else {
+                text.bytes().collect()
+            };
+            if hex.len() != 32 || !hex.iter().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            let mut bytes = [0_u8; 16];
+            for (i, pair) in hex.chunks(2).enumerate() {
+                let pair = std::str::from_utf8(pair).ok()?;
+                bytes[i] = u8::from_str_radix(pair, 16).ok()?;
+            }
+            Some(Uuid { bytes })
+        }
+
+        /// get bytes (big endian).
+        pub fn get_bytes(&self) -> &[u8; 16] {
+            &self.bytes
+        }
+    }
+
+    /// UUID is shown in lower case with hyphens.
+    impl fmt::Display for Uuid {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (i, b) in self.bytes.iter().enumerate() {
+                if [4, 6, 8, 10].contains(&i) {
+                    write!(f, "-")?;
+                }
+                write!(f, "{:02x}", b)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "uuid" {
+            it "parse and format" {
+                let uuid = Uuid::parse("67E55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+                assert_eq!(uuid.get_bytes()[0], 0x67);
+                assert_eq!(uuid.to_string(), format!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+                assert_eq!(Uuid::parse("67e5504410b1426f9247bb680e5fe0c8"), Some(uuid));
+                assert_eq!(Uuid::parse("67e55044-10b1-426f-9247-bb680e5fe0c"), None);
+                assert_eq!(Uuid::parse("67e5504410b1-426f-9247-bb680e5fe0c8"), None);
+                assert_eq!(Uuid::parse("+7e55044-10b1-426f-9247-bb680e5fe0c8"), None);
+            }
+        }
+    }
+}
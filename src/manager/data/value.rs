@@ -0,0 +1,924 @@
+#[allow(clippy::module_inception)]
+pub mod value {
+
+    use crate::manager::data::definition::definition::Type;
+    use crate::manager::data::temporal::temporal::{Date, Time, DateTime};
+    use crate::manager::data::reference::reference::Reference;
+    use crate::manager::data::geo::geo::{Point, BoundingBox};
+    use crate::manager::data::decimal::decimal::Decimal;
+    use crate::manager::data::uuid::uuid::Uuid;
+    use crate::manager::error::error::{FractalError, Result};
+
+    use std::cmp::Ordering;
+    use std::time::Duration;
+
+    /// Data value.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Value {
+        Int(i32),
+        Float(f32),
+        String(String),
+        Bool(bool),
+        I64(i64),
+        U32(u32),
+        U64(u64),
+        F64(f64),
+        Byte(u8),
+        Date(Date),
+        Time(Time),
+        DateTime(DateTime),
+        Duration(Duration),
+        Bytes(Vec<u8>),
+        /// Member name of enumeration.
+        Enum(String),
+        Reference(Reference),
+        IntArray(Vec<i32>),
+        FloatArray(Vec<f32>),
+        I64Array(Vec<i64>),
+        U32Array(Vec<u32>),
+        U64Array(Vec<u64>),
+        F64Array(Vec<f64>),
+        Vector(Vec<f32>),
+        Point(Point),
+        BoundingBox(BoundingBox),
+        Decimal(Decimal),
+        Uuid(Uuid),
+    }
+
+    impl Value {
+        /// get type of value.
+        pub fn get_type(&self) -> Type {
+            match self {
+                Value::Int(_) => Type::Int,
+                Value::Float(_) => Type::Float,
+                Value::String(_) => Type::String,
+                Value::Bool(_) => Type::Bool,
+                Value::I64(_) => Type::I64,
+                Value::U32(_) => Type::U32,
+                Value::U64(_) => Type::U64,
+                Value::F64(_) => Type::F64,
+                Value::Byte(_) => Type::Byte,
+                Value::Date(_) => Type::Date,
+                Value::Time(_) => Type::Time,
+                Value::DateTime(_) => Type::DateTime,
+                Value::Duration(_) => Type::Duration,
+                Value::Bytes(_) => Type::Bytes,
+                Value::Enum(_) => Type::Enum,
+                Value::Reference(_) => Type::Reference,
+                Value::IntArray(_) => Type::IntArray,
+                Value::FloatArray(_) => Type::FloatArray,
+                Value::I64Array(_) => Type::I64Array,
+                Value::U32Array(_) => Type::U32Array,
+                Value::U64Array(_) => Type::U64Array,
+                Value::F64Array(_) => Type::F64Array,
+                Value::Vector(_) => Type::Vector,
+                Value::Point(_) => Type::Point,
+                Value::BoundingBox(_) => Type::BoundingBox,
+                Value::Decimal(_) => Type::Decimal,
+                Value::Uuid(_) => Type::Uuid,
+            }
+        }
+
+        /// Convert value to binary.  
+        /// Enumeration member is converted to its name. `Definition::encode_value` converts it to member code.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            match self {
+                Value::Int(value) => value.to_le_bytes().to_vec(),
+                Value::Float(value) => value.to_le_bytes().to_vec(),
+                Value::String(value) => value.as_bytes().to_vec(),
+                Value::Bool(value) => vec![u8::from(*value)],
+                Value::I64(value) => value.to_le_bytes().to_vec(),
+                Value::U32(value) => value.to_le_bytes().to_vec(),
+                Value::U64(value) => value.to_le_bytes().to_vec(),
+                Value::F64(value) => value.to_le_bytes().to_vec(),
+                Value::Byte(value) => vec![*value],
+                Value::Date(value) => value.get_days().to_le_bytes().to_vec(),
+                Value::Time(value) => value.get_nanos().to_le_bytes().to_vec(),
+                Value::DateTime(value) => {
+                    let mut vec = value.get_epoch_nanos().to_le_bytes().to_vec();
+                    vec.extend_from_slice(&value.get_offset().to_le_bytes());
+                    vec
+                },
+                Value::Duration(value) => {
+                    let mut vec = value.as_secs().to_le_bytes().to_vec();
+                    vec.extend_from_slice(&value.subsec_nanos().to_le_bytes());
+                    vec
+                },
+                Value::Bytes(value) => value.clone(),
+                Value::Enum(value) => value.as_bytes().to_vec(),
+                Value::Reference(value) => value.to_bytes(),
+                Value::IntArray(value) => value.iter().flat_map(|e| e.to_le_bytes()).collect(),
+                Value::FloatArray(value) => value.iter().flat_map(|e| e.to_le_bytes()).collect(),
+                Value::I64Array(value) => value.iter().flat_map(|e| e.to_le_bytes()).collect(),
+                Value::U32Array(value) => value.iter().flat_map(|e| e.to_le_bytes()).collect(),
+                Value::U64Array(value) => value.iter().flat_map(|e| e.to_le_bytes()).collect(),
+                Value::F64Array(value) => value.iter().flat_map(|e| e.to_le_bytes()).collect(),
+                Value::Vector(value) => value.iter().flat_map(|e| e.to_le_bytes()).collect(),
+                Value::Point(value) => point_to_bytes(value),
+                Value::BoundingBox(value) => {
+                    let mut vec = point_to_bytes(&value.get_south_west());
+                    vec.append(&mut point_to_bytes(&value.get_north_east()));
+                    vec
+                },
+                // The scale is declared in definition.
+                Value::Decimal(value) => value.get_mantissa().to_le_bytes().to_vec(),
+                Value::Uuid(value) => value.get_bytes().to_vec(),
+            }
+        }
+
+        /// Convert binary to value.
+        /// * `data_type` - type of value.
+        /// * `bytes` - binary value.
+        pub fn from_bytes(data_type: &Type, bytes: &[u8]) -> Result<Value> {
+            match data_type {
+                Type::Int => Ok(Value::Int(i32::from_le_bytes(fixed_bytes(data_type, bytes)?))),
+                Type::Float => Ok(Value::Float(f32::from_le_bytes(fixed_bytes(data_type, bytes)?))),
+                Type::String => Ok(Value::String(String::from_utf8(bytes.to_vec())?)),
+                Type::Bool => Ok(Value::Bool(fixed_bytes::<1>(data_type, bytes)?[0] != 0)),
+                Type::I64 => Ok(Value::I64(i64::from_le_bytes(fixed_bytes(data_type, bytes)?))),
+                Type::U32 => Ok(Value::U32(u32::from_le_bytes(fixed_bytes(data_type, bytes)?))),
+                Type::U64 => Ok(Value::U64(u64::from_le_bytes(fixed_bytes(data_type, bytes)?))),
+                Type::F64 => Ok(Value::F64(f64::from_le_bytes(fixed_bytes(data_type, bytes)?))),
+                Type::Byte => Ok(Value::Byte(fixed_bytes::<1>(data_type, bytes)?[0])),
+                Type::Date => Ok(Value::Date(Date::from_days(i32::from_le_bytes(fixed_bytes(data_type, bytes)?)))),
+                Type::Time => match Time::from_nanos(u64::from_le_bytes(fixed_bytes(data_type, bytes)?)) {
+                    Some(time) => Ok(Value::Time(time)),
+                    None => Err(FractalError::InvalidValue(Type::Time)),
+                },
+                Type::DateTime => {
+                    let bytes: [u8; 12] = fixed_bytes(data_type, bytes)?;
+                    let epoch_nanos = i64::from_le_bytes(fixed_bytes(data_type, &bytes[0..8])?);
+                    let offset = i32::from_le_bytes(fixed_bytes(data_type, &bytes[8..12])?);
+                    match DateTime::new(epoch_nanos, offset) {
+                        Some(date_time) => Ok(Value::DateTime(date_time)),
+                        None => Err(FractalError::InvalidValue(Type::DateTime)),
+                    }
+                },
+                Type::Duration => {
+                    let bytes: [u8; 12] = fixed_bytes(data_type, bytes)?;
+                    let secs = u64::from_le_bytes(fixed_bytes(data_type, &bytes[0..8])?);
+                    let nanos = u32::from_le_bytes(fixed_bytes(data_type, &bytes[8..12])?);
+                    if nanos >= 1_000_000_000 {
+                        return Err(FractalError::InvalidValue(Type::Duration));
+                    }
+                    Ok(Value::Duration(Duration::new(secs, nanos)))
+                },
+                Type::Bytes => Ok(Value::Bytes(bytes.to_vec())),
+                Type::Enum => Ok(Value::Enum(String::from_utf8(bytes.to_vec())?)),
+                Type::Reference => Ok(Value::Reference(Reference::from_bytes(bytes)?)),
+                Type::IntArray => Ok(Value::IntArray(packed(data_type, bytes, i32::from_le_bytes)?)),
+                Type::FloatArray => Ok(Value::FloatArray(packed(data_type, bytes, f32::from_le_bytes)?)),
+                Type::I64Array => Ok(Value::I64Array(packed(data_type, bytes, i64::from_le_bytes)?)),
+                Type::U32Array => Ok(Value::U32Array(packed(data_type, bytes, u32::from_le_bytes)?)),
+                Type::U64Array => Ok(Value::U64Array(packed(data_type, bytes, u64::from_le_bytes)?)),
+                Type::F64Array => Ok(Value::F64Array(packed(data_type, bytes, f64::from_le_bytes)?)),
+                Type::Vector => Ok(Value::Vector(packed(data_type, bytes, f32::from_le_bytes)?)),
+                Type::Point => Ok(Value::Point(bytes_to_point(&fixed_bytes::<16>(data_type, bytes)?)?)),
+                // The scale is given by `Definition::decode_value`.
+                Type::Decimal => match Decimal::new(i128::from_le_bytes(fixed_bytes(data_type, bytes)?), 0) {
+                    Some(decimal) => Ok(Value::Decimal(decimal)),
+                    None => Err(FractalError::InvalidValue(Type::Decimal)),
+                },
+                Type::Uuid => Ok(Value::Uuid(Uuid::from_bytes(fixed_bytes(data_type, bytes)?))),
+                // It is converted by `Definition::decode_value`.
+                Type::Custom(code) => Err(FractalError::UnregisteredCodec(*code)),
+                Type::BoundingBox => {
+                    let bytes: [u8; 32] = fixed_bytes(data_type, bytes)?;
+                    let south_west = bytes_to_point(&bytes[0..16])?;
+                    let north_east = bytes_to_point(&bytes[16..32])?;
+                    match BoundingBox::new(south_west, north_east) {
+                        Some(area) => Ok(Value::BoundingBox(area)),
+                        None => Err(FractalError::InvalidValue(Type::BoundingBox)),
+                    }
+                },
+            }
+        }
+
+        /// get value as i32.
+        pub fn as_i32(&self) -> Option<i32> {
+            match self {
+                Value::Int(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as f32.
+        pub fn as_f32(&self) -> Option<f32> {
+            match self {
+                Value::Float(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as str.
+        /// Enumeration member is got as its name.
+        pub fn as_str(&self) -> Option<&str> {
+            match self {
+                Value::String(value) | Value::Enum(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as bool.
+        pub fn as_bool(&self) -> Option<bool> {
+            match self {
+                Value::Bool(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as i64.
+        pub fn as_i64(&self) -> Option<i64> {
+            match self {
+                Value::I64(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as u32.
+        pub fn as_u32(&self) -> Option<u32> {
+            match self {
+                Value::U32(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as u64.
+        pub fn as_u64(&self) -> Option<u64> {
+            match self {
+                Value::U64(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as f64.
+        pub fn as_f64(&self) -> Option<f64> {
+            match self {
+                Value::F64(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as u8.
+        pub fn as_u8(&self) -> Option<u8> {
+            match self {
+                Value::Byte(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as date.
+        pub fn as_date(&self) -> Option<Date> {
+            match self {
+                Value::Date(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as time.
+        pub fn as_time(&self) -> Option<Time> {
+            match self {
+                Value::Time(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as date time.
+        pub fn as_date_time(&self) -> Option<DateTime> {
+            match self {
+                Value::DateTime(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as duration.
+        pub fn as_duration(&self) -> Option<Duration> {
+            match self {
+                Value::Duration(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as binary payload.
+        pub fn as_bytes(&self) -> Option<&[u8]> {
+            match self {
+                Value::Bytes(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as slice of i32.
+        pub fn as_i32_slice(&self) -> Option<&[i32]> {
+            match self {
+                Value::IntArray(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as slice of f32.
+        pub fn as_f32_slice(&self) -> Option<&[f32]> {
+            match self {
+                Value::FloatArray(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as slice of i64.
+        pub fn as_i64_slice(&self) -> Option<&[i64]> {
+            match self {
+                Value::I64Array(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as slice of u32.
+        pub fn as_u32_slice(&self) -> Option<&[u32]> {
+            match self {
+                Value::U32Array(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as slice of u64.
+        pub fn as_u64_slice(&self) -> Option<&[u64]> {
+            match self {
+                Value::U64Array(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as slice of f64.
+        pub fn as_f64_slice(&self) -> Option<&[f64]> {
+            match self {
+                Value::F64Array(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as vector.
+        pub fn as_vector(&self) -> Option<&[f32]> {
+            match self {
+                Value::Vector(value) => Some(value),
+                _ => None,
+            }
+        }
+
+        /// get value as point.
+        pub fn as_point(&self) -> Option<Point> {
+            match self {
+                Value::Point(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as bounding box.
+        pub fn as_bounding_box(&self) -> Option<BoundingBox> {
+            match self {
+                Value::BoundingBox(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as decimal.
+        pub fn as_decimal(&self) -> Option<Decimal> {
+            match self {
+                Value::Decimal(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as UUID.
+        pub fn as_uuid(&self) -> Option<Uuid> {
+            match self {
+                Value::Uuid(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        /// get value as reference.
+        pub fn as_reference(&self) -> Option<&Reference> {
+            match self {
+                Value::Reference(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    /// Values are comparable only if they have the same type.  
+    /// Temporal values are compared chronologically.
+    impl PartialOrd for Value {
+        fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+            match (self, other) {
+                (Value::Int(a), Value::Int(b)) => a.partial_cmp(b),
+                (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+                (Value::String(a), Value::String(b)) => a.partial_cmp(b),
+                (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
+                (Value::I64(a), Value::I64(b)) => a.partial_cmp(b),
+                (Value::U32(a), Value::U32(b)) => a.partial_cmp(b),
+                (Value::U64(a), Value::U64(b)) => a.partial_cmp(b),
+                (Value::F64(a), Value::F64(b)) => a.partial_cmp(b),
+                (Value::Byte(a), Value::Byte(b)) => a.partial_cmp(b),
+                (Value::Date(a), Value::Date(b)) => a.partial_cmp(b),
+                (Value::Time(a), Value::Time(b)) => a.partial_cmp(b),
+                (Value::DateTime(a), Value::DateTime(b)) => a.partial_cmp(b),
+                (Value::Duration(a), Value::Duration(b)) => a.partial_cmp(b),
+                (Value::Bytes(a), Value::Bytes(b)) => a.partial_cmp(b),
+                // Enumeration members are not ordered.
+                (Value::Enum(a), Value::Enum(b)) if a == b => Some(Ordering::Equal),
+                (Value::Reference(a), Value::Reference(b)) if a == b => Some(Ordering::Equal),
+                (Value::IntArray(a), Value::IntArray(b)) => a.partial_cmp(b),
+                (Value::FloatArray(a), Value::FloatArray(b)) => a.partial_cmp(b),
+                (Value::I64Array(a), Value::I64Array(b)) => a.partial_cmp(b),
+                (Value::U32Array(a), Value::U32Array(b)) => a.partial_cmp(b),
+                (Value::U64Array(a), Value::U64Array(b)) => a.partial_cmp(b),
+                (Value::F64Array(a), Value::F64Array(b)) => a.partial_cmp(b),
+                (Value::Vector(a), Value::Vector(b)) => a.partial_cmp(b),
+                // Geographic values are not ordered.
+                (Value::Point(a), Value::Point(b)) if a == b => Some(Ordering::Equal),
+                (Value::BoundingBox(a), Value::BoundingBox(b)) if a == b => Some(Ordering::Equal),
+                // Decimals are compared exactly even if their scales are different.
+                (Value::Decimal(a), Value::Decimal(b)) => a.partial_cmp(b),
+                (Value::Uuid(a), Value::Uuid(b)) => a.partial_cmp(b),
+                _ => None,
+            }
+        }
+    }
+
+    /// get fixed size bytes.  
+    /// Return error if the length is not the size, so trailing bytes are not ignored.
+    /// * `data_type` - type of value.
+    /// * `bytes` - binary value.
+    fn fixed_bytes<const N: usize>(data_type: &Type, bytes: &[u8]) -> Result<[u8; N]> {
+        match bytes.try_into() {
+            Ok(bytes) => Ok(bytes),
+            Err(_) => Err(FractalError::InvalidValue(*data_type)),
+        }
+    }
+
+    /// get packed values.
+    /// * `data_type` - type of array.
+    /// * `bytes` - binary value.
+    /// * `f` - convert binary to element.
+    fn packed<T, const N: usize>(data_type: &Type, bytes: &[u8], f: fn([u8; N]) -> T) -> Result<Vec<T>> {
+        if !bytes.len().is_multiple_of(N) {
+            return Err(FractalError::InvalidValue(*data_type));
+        }
+        bytes.chunks_exact(N).map(|chunk| fixed_bytes(data_type, chunk).map(f)).collect()
+    }
+
+    /// Convert point to binary (latitude and longitude).
+    fn point_to_bytes(point: &Point) -> Vec<u8> {
+        let mut vec = point.get_lat().to_le_bytes().to_vec();
+        vec.extend_from_slice(&point.get_lon().to_le_bytes());
+        vec
+    }
+
+    /// Convert binary to point.
+    fn bytes_to_point(bytes: &[u8]) -> Result<Point> {
+        let lat = f64::from_le_bytes(fixed_bytes(&Type::Point, &bytes[0..8])?);
+        let lon = f64::from_le_bytes(fixed_bytes(&Type::Point, &bytes[8..16])?);
+        match Point::new(lat, lon) {
+            Some(point) => Ok(point),
+            None => Err(FractalError::InvalidValue(Type::Point)),
+        }
+    }
+
+    impl From<i32> for Value {
+        fn from(value: i32) -> Value {
+            Value::Int(value)
+        }
+    }
+
+    impl From<f32> for Value {
+        fn from(value: f32) -> Value {
+            Value::Float(value)
+        }
+    }
+
+    impl From<String> for Value {
+        fn from(value: String) -> Value {
+            Value::String(value)
+        }
+    }
+
+    impl From<&str> for Value {
+        fn from(value: &str) -> Value {
+            Value::String(value.to_string())
+        }
+    }
+
+    impl From<bool> for Value {
+        fn from(value: bool) -> Value {
+            Value::Bool(value)
+        }
+    }
+
+    impl From<i64> for Value {
+        fn from(value: i64) -> Value {
+            Value::I64(value)
+        }
+    }
+
+    impl From<u32> for Value {
+        fn from(value: u32) -> Value {
+            Value::U32(value)
+        }
+    }
+
+    impl From<u64> for Value {
+        fn from(value: u64) -> Value {
+            Value::U64(value)
+        }
+    }
+
+    impl From<f64> for Value {
+        fn from(value: f64) -> Value {
+            Value::F64(value)
+        }
+    }
+
+    impl From<u8> for Value {
+        fn from(value: u8) -> Value {
+            Value::Byte(value)
+        }
+    }
+
+    impl From<Date> for Value {
+        fn from(value: Date) -> Value {
+            Value::Date(value)
+        }
+    }
+
+    impl From<Time> for Value {
+        fn from(value: Time) -> Value {
+            Value::Time(value)
+        }
+    }
+
+    impl From<DateTime> for Value {
+        fn from(value: DateTime) -> Value {
+            Value::DateTime(value)
+        }
+    }
+
+    impl From<Duration> for Value {
+        fn from(value: Duration) -> Value {
+            Value::Duration(value)
+        }
+    }
+
+    impl From<Vec<u8>> for Value {
+        fn from(value: Vec<u8>) -> Value {
+            Value::Bytes(value)
+        }
+    }
+
+    impl From<&[u8]> for Value {
+        fn from(value: &[u8]) -> Value {
+            Value::Bytes(value.to_vec())
+        }
+    }
+
+    impl From<Vec<i32>> for Value {
+        fn from(value: Vec<i32>) -> Value {
+            Value::IntArray(value)
+        }
+    }
+
+    impl From<Vec<f32>> for Value {
+        fn from(value: Vec<f32>) -> Value {
+            Value::FloatArray(value)
+        }
+    }
+
+    impl From<Vec<i64>> for Value {
+        fn from(value: Vec<i64>) -> Value {
+            Value::I64Array(value)
+        }
+    }
+
+    impl From<Vec<u32>> for Value {
+        fn from(value: Vec<u32>) -> Value {
+            Value::U32Array(value)
+        }
+    }
+
+    impl From<Vec<u64>> for Value {
+        fn from(value: Vec<u64>) -> Value {
+            Value::U64Array(value)
+        }
+    }
+
+    impl From<Vec<f64>> for Value {
+        fn from(value: Vec<f64>) -> Value {
+            Value::F64Array(value)
+        }
+    }
+
+    impl From<Point> for Value {
+        fn from(value: Point) -> Value {
+            Value::Point(value)
+        }
+    }
+
+    impl From<BoundingBox> for Value {
+        fn from(value: BoundingBox) -> Value {
+            Value::BoundingBox(value)
+        }
+    }
+
+    impl From<Decimal> for Value {
+        fn from(value: Decimal) -> Value {
+            Value::Decimal(value)
+        }
+    }
+
+    impl From<Uuid> for Value {
+        fn from(value: Uuid) -> Value {
+            Value::Uuid(value)
+        }
+    }
+
+    impl From<Reference> for Value {
+        fn from(value: Reference) -> Value {
+            Value::Reference(value)
+        }
+    }
+
+    /// Type which is able to get from value.
+    pub trait FromValue: Sized {
+        /// Convert value. Return None if the type of value is different.
+        /// * `value` - data value.
+        fn from_value(value: Value) -> Option<Self>;
+    }
+
+    impl FromValue for Value {
+        fn from_value(value: Value) -> Option<Value> {
+            Some(value)
+        }
+    }
+
+    impl FromValue for i32 {
+        fn from_value(value: Value) -> Option<i32> {
+            value.as_i32()
+        }
+    }
+
+    impl FromValue for f32 {
+        fn from_value(value: Value) -> Option<f32> {
+            value.as_f32()
+        }
+    }
+
+    impl FromValue for bool {
+        fn from_value(value: Value) -> Option<bool> {
+            value.as_bool()
+        }
+    }
+
+    impl FromValue for i64 {
+        fn from_value(value: Value) -> Option<i64> {
+            value.as_i64()
+        }
+    }
+
+    impl FromValue for u32 {
+        fn from_value(value: Value) -> Option<u32> {
+            value.as_u32()
+        }
+    }
+
+    impl FromValue for u64 {
+        fn from_value(value: Value) -> Option<u64> {
+            value.as_u64()
+        }
+    }
+
+    impl FromValue for f64 {
+        fn from_value(value: Value) -> Option<f64> {
+            value.as_f64()
+        }
+    }
+
+    impl FromValue for u8 {
+        fn from_value(value: Value) -> Option<u8> {
+            value.as_u8()
+        }
+    }
+
+    impl FromValue for Date {
+        fn from_value(value: Value) -> Option<Date> {
+            value.as_date()
+        }
+    }
+
+    impl FromValue for Time {
+        fn from_value(value: Value) -> Option<Time> {
+            value.as_time()
+        }
+    }
+
+    impl FromValue for DateTime {
+        fn from_value(value: Value) -> Option<DateTime> {
+            value.as_date_time()
+        }
+    }
+
+    impl FromValue for Duration {
+        fn from_value(value: Value) -> Option<Duration> {
+            value.as_duration()
+        }
+    }
+
+    impl FromValue for Vec<u8> {
+        fn from_value(value: Value) -> Option<Vec<u8>> {
+            match value {
+                Value::Bytes(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for Vec<i32> {
+        fn from_value(value: Value) -> Option<Vec<i32>> {
+            match value {
+                Value::IntArray(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for Vec<f32> {
+        fn from_value(value: Value) -> Option<Vec<f32>> {
+            match value {
+                Value::FloatArray(value) | Value::Vector(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for Vec<i64> {
+        fn from_value(value: Value) -> Option<Vec<i64>> {
+            match value {
+                Value::I64Array(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for Vec<u32> {
+        fn from_value(value: Value) -> Option<Vec<u32>> {
+            match value {
+                Value::U32Array(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for Vec<u64> {
+        fn from_value(value: Value) -> Option<Vec<u64>> {
+            match value {
+                Value::U64Array(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for Vec<f64> {
+        fn from_value(value: Value) -> Option<Vec<f64>> {
+            match value {
+                Value::F64Array(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for Point {
+        fn from_value(value: Value) -> Option<Point> {
+            value.as_point()
+        }
+    }
+
+    impl FromValue for BoundingBox {
+        fn from_value(value: Value) -> Option<BoundingBox> {
+            value.as_bounding_box()
+        }
+    }
+
+    impl FromValue for Decimal {
+        fn from_value(value: Value) -> Option<Decimal> {
+            value.as_decimal()
+        }
+    }
+
+    impl FromValue for Uuid {
+        fn from_value(value: Value) -> Option<Uuid> {
+            value.as_uuid()
+        }
+    }
+
+    impl FromValue for Reference {
+        fn from_value(value: Value) -> Option<Reference> {
+            match value {
+                Value::Reference(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromValue for String {
+        fn from_value(value: Value) -> Option<String> {
+            match value {
+                Value::String(value) | Value::Enum(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        use crate::manager::data::temporal::temporal::NANOS_PER_DAY;
+
+        describe "value" {
+            it "from" {
+                assert_eq!(Value::from(3), Value::Int(3));
+                assert_eq!(Value::from(4.5_f32), Value::Float(4.5));
+                assert_eq!(Value::from("taro"), Value::String("taro".to_string()));
+            }
+
+            it "accessor" {
+                assert_eq!(Value::Int(3).as_i32(), Some(3));
+                assert_eq!(Value::Int(3).as_f32(), None);
+                assert_eq!(Value::from("taro").as_str(), Some("taro"));
+                assert_eq!(Value::Enum("active".to_string()).as_str(), Some("active"));
+                assert_eq!(Value::from(vec![1_i32, 2]).as_i32_slice(), Some(&[1_i32, 2][..]));
+                assert_eq!(Value::from(vec![1_i32, 2]).as_f32_slice(), None);
+            }
+
+            it "compare" {
+                let early = DateTime::new(0, 9 * 3600).unwrap();
+                let late = DateTime::new(1, -9 * 3600).unwrap();
+                assert!(Value::from(early) < Value::from(late));
+                assert!(Value::from(Duration::from_secs(1)) > Value::from(Duration::from_millis(999)));
+                assert_eq!(Value::Int(1).partial_cmp(&Value::I64(1)), None);
+                assert_eq!(Value::Enum("a".to_string()).partial_cmp(&Value::Enum("b".to_string())), None);
+            }
+
+            it "invalid binary" {
+                let bytes = (NANOS_PER_DAY).to_le_bytes();
+                match Value::from_bytes(&Type::Time, &bytes) {
+                    Err(FractalError::InvalidValue(Type::Time)) => (),
+                    _ => panic!("invalid time is not detected"),
+                }
+                match Value::from_bytes(&Type::IntArray, &bytes[0..6]) {
+                    Err(FractalError::InvalidValue(Type::IntArray)) => (),
+                    _ => panic!("invalid array is not detected"),
+                }
+                // Fixed size value does not ignore trailing bytes.
+                for data_type in [Type::Int, Type::Bool, Type::I64, Type::U64, Type::F64, Type::Date, Type::Time,
+                    Type::Point, Type::Decimal, Type::Uuid] {
+                    let bytes = vec![0_u8; 33];
+                    match Value::from_bytes(&data_type, &bytes) {
+                        Err(FractalError::InvalidValue(t)) => assert_eq!(t, data_type),
+                        _ => panic!("trailing bytes are not detected"),
+                    }
+                    assert!(Value::from_bytes(&data_type, &[]).is_err());
+                }
+                let mut bytes = 0_i64.to_le_bytes().to_vec();
+                bytes.extend_from_slice(&i32::MIN.to_le_bytes());
+                match Value::from_bytes(&Type::DateTime, &bytes) {
+                    Err(FractalError::InvalidValue(Type::DateTime)) => (),
+                    _ => panic!("invalid offset is not detected"),
+                }
+            }
+
+            it "binary r/w" {
+                let values = [
+                    Value::Int(-3), Value::Float(4.5), Value::from("taro"), Value::Bool(true),
+                    Value::I64(-0x0102_0304_0506_0708), Value::U32(0xffff_fffe), Value::U64(u64::MAX),
+                    Value::F64(-1.25e300), Value::Byte(0x03),
+                    Value::Date(Date::from_days(-1)), Value::Time(Time::from_nanos(1).unwrap()),
+                    Value::DateTime(DateTime::new(-1, -3600).unwrap()), Value::Duration(Duration::new(3, 4)),
+                    Value::from(vec![0x02_u8, 0x03]), Value::from(Reference::new(None, &[(3, None)]).unwrap()),
+                    Value::from(vec![-1_i32, 2]), Value::from(vec![4.5_f32]), Value::from(vec![-1_i64]),
+                    Value::from(vec![1_u32, 2, 3]), Value::from(Vec::<u64>::new()), Value::from(vec![0.1_f64, -0.2]),
+                    Value::Vector(vec![1.0, -1.0]), Value::from(Point::new(35.0, 139.0).unwrap()),
+                    Value::from(BoundingBox::new(Point::new(-1.0, 170.0).unwrap(), Point::new(1.0, -170.0).unwrap()).unwrap()),
+                    Value::from(Decimal::new(-12_345, 0).unwrap()),
+                    Value::from(Uuid::parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()),
+                ];
+                for value in values {
+                    assert_eq!(value.get_type().size().unwrap_or(value.to_bytes().len()), value.to_bytes().len());
+                    let bytes = value.to_bytes();
+                    assert_eq!(Value::from_bytes(&value.get_type(), &bytes).unwrap(), value);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+#[allow(clippy::module_inception)]
+pub mod vector {
+
+    use crate::manager::data::data::Data;
+
+    /// Number of refinement of cluster centroids.
+    const KMEANS_ITERATIONS: usize = 4;
+
+    /// Distance between vectors.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Distance {
+        /// 1 - cosine similarity.
+        Cosine,
+        /// Euclidean distance.
+        L2,
+    }
+
+    impl Distance {
+        /// calculate distance between vectors.
+        /// * `a` - vector.
+        /// * `b` - vector which has the same dimension as `a`.
+        pub fn between(&self, a: &[f32], b: &[f32]) -> f32 {
+            match self {
+                Distance::Cosine => {
+                    let dot: f32 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+                    let norm = norm(a) * norm(b);
+                    // Zero vector is not similar to any vector.
+                    if norm == 0.0 { 1.0 } else { 1.0 - dot / norm }
+                },
+                Distance::L2 => a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum::<f32>().sqrt(),
+            }
+        }
+    }
+
+    /// Result of nearest neighbour search.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Neighbor {
+        /// index path of found data from the parent data.
+        pub index: Vec<usize>,
+        /// data root of found data. None if the data root cannot select it.
+        pub root: Option<Vec<(u32, Option<Data>)>>,
+        /// distance from the query.
+        pub distance: f32,
+    }
+
+    /// Approximate nearest neighbour index (inverted file).
+    /// Vectors are grouped in clusters, and only the clusters near the query are scanned.
+    #[derive(Clone, Debug)]
+    pub struct VectorIndex {
+        distance: Distance,
+        centroids: Vec<Vec<f32>>,
+        /// index path from the parent data and vector of each cluster.
+        lists: Vec<Vec<(Vec<usize>, Vec<f32>)>>,
+    }
+
+    impl VectorIndex {
+        /// Build index.
+        /// * `distance` - distance which is used by search.
+        /// * `entries` - index path from the parent data and vector.
+        pub fn build(distance: Distance, entries: Vec<(Vec<usize>, Vec<f32>)>) -> VectorIndex {
+            let len = entries.len();
+            let cluster_len = (len as f64).sqrt().ceil() as usize;
+            // Initial centroids are sampled at regular intervals.
+            let mut centroids: Vec<Vec<f32>> = (0..cluster_len)
+                .map(|i| entries[i * len / cluster_len].1.clone())
+                .collect();
+            for _ in 0..KMEANS_ITERATIONS {
+                let mut sums = vec![Vec::new(); cluster_len];
+                let mut counts = vec![0_usize; cluster_len];
+                for (_, vector) in &entries {
+                    let i = nearest_centroid(distance, &centroids, vector);
+                    if sums[i].is_empty() {
+                        sums[i] = vec![0.0_f32; vector.len()];
+                    }
+                    sums[i].iter_mut().zip(vector).for_each(|(sum, e)| *sum += e);
+                    counts[i] += 1;
+                }
+                for (i, sum) in sums.into_iter().enumerate() {
+                    // Empty cluster keeps its centroid.
+                    if counts[i] > 0 {
+                        centroids[i] = sum.into_iter().map(|e| e / counts[i] as f32).collect();
+                    }
+                }
+            }
+            let mut lists = vec![Vec::new(); cluster_len];
+            for (index, vector) in entries {
+                lists[nearest_centroid(distance, &centroids, &vector)].push((index, vector));
+            }
+            VectorIndex {
+                distance,
+                centroids,
+                lists,
+            }
+        }
+
+        /// get distance which is used by search.
+        pub fn get_distance(&self) -> Distance {
+            self.distance
+        }
+
+        /// search k nearest vectors.
+        /// Return index path and distance in ascending order of distance.
+        /// * `query` - query vector.
+        /// * `k` - number of results.
+        pub fn search(&self, query: &[f32], k: usize) -> Vec<(Vec<usize>, f32)> {
+            let mut clusters: Vec<(usize, f32)> = self.centroids.iter()
+                .map(|centroid| self.distance.between(centroid, query))
+                .enumerate()
+                .collect();
+            clusters.sort_by(|a, b| a.1.total_cmp(&b.1));
+            // Scan the nearest clusters until k vectors are found.
+            let probe = (self.centroids.len() as f64).sqrt().ceil() as usize;
+            let mut found = Vec::new();
+            for (i, (cluster, _)) in clusters.into_iter().enumerate() {
+                if i >= probe && found.len() >= k {
+                    break;
+                }
+                for (index, vector) in &self.lists[cluster] {
+                    found.push((index.clone(), self.distance.between(vector, query)));
+                }
+            }
+            found.sort_by(|a, b| a.1.total_cmp(&b.1));
+            found.truncate(k);
+            found
+        }
+    }
+
+    /// get norm of vector.
+    fn norm(vector: &[f32]) -> f32 {
+        vector.iter().map(|e| e * e).sum::<f32>().sqrt()
+    }
+
+    /// get index of the nearest centroid.
+    fn nearest_centroid(distance: Distance, centroids: &[Vec<f32>], vector: &[f32]) -> usize {
+        let mut nearest = (0, f32::INFINITY);
+        for (i, centroid) in centroids.iter().enumerate() {
+            let d = distance.between(centroid, vector);
+            if d < nearest.1 {
+                nearest = (i, d);
+            }
+        }
+        nearest.0
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "vector" {
+            it "distance" {
+                assert_eq!(Distance::L2.between(&[0.0, 3.0], &[4.0, 0.0]), 5.0);
+                assert_eq!(Distance::Cosine.between(&[1.0, 0.0], &[2.0, 0.0]), 0.0);
+                assert_eq!(Distance::Cosine.between(&[1.0, 0.0], &[0.0, 2.0]), 1.0);
+                assert_eq!(Distance::Cosine.between(&[0.0, 0.0], &[0.0, 2.0]), 1.0);
+            }
+
+            it "index search" {
+                let entries: Vec<(Vec<usize>, Vec<f32>)> = (0..100)
+                    .map(|i| (vec![i], vec![(i % 10) as f32, (i / 10) as f32]))
+                    .collect();
+                let index = VectorIndex::build(Distance::L2, entries);
+                let found = index.search(&[3.1, 4.2], 3);
+                assert_eq!(found.len(), 3);
+                assert_eq!(found[0].0, vec![43]);
+                assert!(found[0].1 <= found[1].1 && found[1].1 <= found[2].1);
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+#[allow(clippy::module_inception)]
+pub mod error {
+
+    use crate::manager::data::definition::definition::Type;
+    use crate::manager::data::decimal::decimal::MAX_SCALE;
+    use crate::manager::io_for_db::header::header::FileKind;
+
+    use std::fmt;
+    use std::io;
+    use std::str::Utf8Error;
+    use std::string::FromUtf8Error;
+
+    /// Result type of OurFractal.
+    pub type Result<T> = std::result::Result<T, FractalError>;
+
+    /// Error of OurFractal.
+    #[derive(Debug)]
+    pub enum FractalError {
+        /// The tag is already defined.
+        DuplicateTag(u32),
+        /// The tag is not defined.
+        UnknownTag(u32),
+        /// The value does not match the definition type.
+        TypeMismatch {
+            tag: u32,
+            expected: Type,
+        },
+        /// The binary value is not valid for the type.
+        InvalidValue(Type),
+        /// The type code read from file is unknown.
+        UnknownTypeCode(u8),
+        /// The tag of numeric constraint bound read from file is unknown.
+        UnknownNumberTag(u8),
+        /// The check sum of the record is not correct.
+        ChecksumMismatch {
+            /// offset of the record in file.
+            offset: usize,
+            /// tag of the record.
+            tag: Option<u32>,
+            expected: u64,
+            actual: u64,
+        },
+        /// The binary data ended before the value was read.
+        Truncated {
+            index: usize,
+            len: usize,
+        },
+        /// The record does not start with STX or end with ETX.
+        InvalidRecord {
+            index: usize,
+        },
+        /// The record is too large to write.
+        RecordTooLarge(usize),
+        /// The format version of file is not supported.
+        UnsupportedVersion(u8),
+        /// The file header is broken.
+        InvalidHeader,
+        /// The file is not the expected kind.
+        FileKindMismatch {
+            expected: FileKind,
+            actual: FileKind,
+        },
+        /// The data file is written against other definitions.
+        DefinitionMismatch {
+            expected: u64,
+            actual: u64,
+        },
+        /// The value is not a member of the enumeration.
+        UnknownMember {
+            tag: u32,
+            name: String,
+        },
+        /// The enumeration members are duplicated or too many.
+        InvalidMembers(u32),
+        /// The index is out of the array.
+        IndexOutOfRange {
+            tag: u32,
+            index: usize,
+            len: usize,
+        },
+        /// The dimension of vector is not the same as definition.
+        DimensionMismatch {
+            tag: u32,
+            expected: usize,
+            actual: usize,
+        },
+        /// The decimal cannot be represented in the scale of definition without rounding.
+        ScaleMismatch {
+            tag: u32,
+            scale: u8,
+        },
+        /// The scale of decimal is too large.
+        InvalidScale(u32),
+        /// The text cannot be parsed as the value of tag.
+        InvalidText {
+            tag: u32,
+            text: String,
+        },
+        /// The child tag is not in the children of the parent definition.
+        ChildNotAllowed {
+            parent: u32,
+            child: u32,
+        },
+        /// The tag which is not multiple appears more than once in the parent.
+        DuplicateChild {
+            parent: u32,
+            child: u32,
+        },
+        /// The number of the child data is out of the cardinality of the parent definition.
+        Cardinality {
+            parent: u32,
+            child: u32,
+            count: usize,
+        },
+        /// The min number of the child data is larger than the max number.
+        InvalidCardinality {
+            parent: u32,
+            child: u32,
+        },
+        /// The value is less than min or larger than max of definition.
+        ValueOutOfRange(u32),
+        /// The string does not match the pattern of definition.
+        PatternMismatch(u32),
+        /// The value is longer than max length of definition.
+        TooLong {
+            tag: u32,
+            len: usize,
+            max: u32,
+        },
+        /// The value is empty but definition requires not empty.
+        EmptyValue(u32),
+        /// The pattern is not regular expression.
+        InvalidPattern(String),
+        /// The required child cannot be made because its definition has no default value.
+        NoDefault(u32),
+        /// The definition requires itself as descendant.
+        CyclicDefinition(u32),
+        /// The data is nested deeper than the max depth of definition or `MAX_DATA_DEPTH`.
+        /// The depth is the number of the data of the tag, or the nesting of all data for `MAX_DATA_DEPTH`.
+        DepthExceeded {
+            tag: u32,
+            depth: usize,
+        },
+        /// The child tag of definition is not defined.
+        DanglingChild {
+            parent: u32,
+            child: u32,
+        },
+        /// The definition is not a descendant of any top level definition.
+        UnreachableDefinition(u32),
+        /// The name is used by more than one definition.
+        DuplicateName(String),
+        /// The definition is used by data, the parent data or other definitions.
+        DefinitionInUse(u32),
+        /// The definition cannot be extended.
+        NotBase(u32),
+        /// The codec of user defined type is not registered.
+        UnregisteredCodec(u8),
+        /// The type code cannot be used for user defined type.
+        InvalidCustomCode(u8),
+        /// The definition is not nullable.
+        NotNullable(u32),
+        /// The value is null.
+        NullValue(u32),
+        /// The reference does not point to any data.
+        DanglingReference(String),
+        /// The reference points to more than one data.
+        AmbiguousReference(String),
+        /// The data cannot be a key of reference because its value is null or stored in blob file.
+        InvalidKey(u32),
+        /// The content of blob file does not match the hash in the record.
+        BlobMismatch(u64),
+        /// The child binary manager is not created.
+        MissingChild,
+        /// The data root does not point to any data.
+        DataNotFound,
+        /// The string is not valid UTF-8.
+        Utf8(Utf8Error),
+        /// File I/O error.
+        Io(io::Error),
+    }
+
+    impl fmt::Display for FractalError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                FractalError::DuplicateTag(tag) => write!(f, "tag {:08x} is already defined", tag),
+                FractalError::UnknownTag(tag) => write!(f, "tag {:08x} is not defined", tag),
+                FractalError::TypeMismatch { tag, expected } =>
+                    write!(f, "value of tag {:08x} must be {:?}", tag, expected),
+                FractalError::InvalidValue(data_type) => write!(f, "binary value is invalid for {:?}", data_type),
+                FractalError::UnknownTypeCode(code) => write!(f, "type code {:02x} is unknown", code),
+                FractalError::UnknownNumberTag(tag) => write!(f, "number tag {:02x} is unknown", tag),
+                FractalError::ChecksumMismatch { offset, tag: Some(tag), expected, actual } =>
+                    write!(f, "check sum error at {} (tag {:08x}, expected {:x}, actual {:x})", offset, tag, expected, actual),
+                FractalError::ChecksumMismatch { offset, tag: None, expected, actual } =>
+                    write!(f, "check sum error at {} (expected {:x}, actual {:x})", offset, expected, actual),
+                FractalError::Truncated { index, len } =>
+                    write!(f, "data is truncated (index {}, length {})", index, len),
+                FractalError::InvalidRecord { index } => write!(f, "record at {} is invalid", index),
+                FractalError::RecordTooLarge(len) => write!(f, "record size {} is too large", len),
+                FractalError::UnsupportedVersion(version) =>
+                    write!(f, "format version {} is not supported", version),
+                FractalError::InvalidHeader => write!(f, "file header is invalid"),
+                FractalError::FileKindMismatch { expected, actual } =>
+                    write!(f, "file must be {:?} file but {:?} file", expected, actual),
+                FractalError::DefinitionMismatch { expected, actual } =>
+                    write!(f, "data file is written against definitions {:016x} but current definitions are {:016x}",
+                        actual, expected),
+                FractalError::UnknownMember { tag, name } =>
+                    write!(f, "{:?} is not a member of tag {:08x}", name, tag),
+                FractalError::InvalidMembers(tag) =>
+                    write!(f, "members of tag {:08x} are duplicated or too many", tag),
+                FractalError::IndexOutOfRange { tag, index, len } =>
+                    write!(f, "index {} is out of array of tag {:08x} (length {})", index, tag, len),
+                FractalError::DimensionMismatch { tag, expected, actual } =>
+                    write!(f, "dimension of tag {:08x} must be {} but {}", tag, expected, actual),
+                FractalError::ScaleMismatch { tag, scale } =>
+                    write!(f, "decimal of tag {:08x} cannot be represented in scale {}", tag, scale),
+                FractalError::InvalidScale(tag) =>
+                    write!(f, "scale of tag {:08x} is larger than {}", tag, MAX_SCALE),
+                FractalError::InvalidText { tag, text } =>
+                    write!(f, "{:?} is not a value of tag {:08x}", text, tag),
+                FractalError::ChildNotAllowed { parent, child } =>
+                    write!(f, "tag {:08x} is not a child of tag {:08x}", child, parent),
+                FractalError::DuplicateChild { parent, child } =>
+                    write!(f, "tag {:08x} is not multiple but appears twice in tag {:08x}", child, parent),
+                FractalError::Cardinality { parent, child, count } =>
+                    write!(f, "number of tag {:08x} in tag {:08x} is out of range ({})", child, parent, count),
+                FractalError::InvalidCardinality { parent, child } =>
+                    write!(f, "min number of tag {:08x} in tag {:08x} is larger than max number", child, parent),
+                FractalError::ValueOutOfRange(tag) => write!(f, "value of tag {:08x} is out of range", tag),
+                FractalError::PatternMismatch(tag) => write!(f, "value of tag {:08x} does not match the pattern", tag),
+                FractalError::TooLong { tag, len, max } =>
+                    write!(f, "length of tag {:08x} must be {} or less but {}", tag, max, len),
+                FractalError::EmptyValue(tag) => write!(f, "value of tag {:08x} is empty", tag),
+                FractalError::InvalidPattern(pattern) => write!(f, "{:?} is not regular expression", pattern),
+                FractalError::NoDefault(tag) => write!(f, "tag {:08x} has no default value", tag),
+                FractalError::CyclicDefinition(tag) => write!(f, "definition of tag {:08x} requires itself", tag),
+                FractalError::DepthExceeded { tag, depth } =>
+                    write!(f, "data of tag {:08x} is nested too deep (depth {})", tag, depth),
+                FractalError::DanglingChild { parent, child } =>
+                    write!(f, "child tag {:08x} of tag {:08x} is not defined", child, parent),
+                FractalError::UnreachableDefinition(tag) =>
+                    write!(f, "tag {:08x} is not reachable from top level definitions", tag),
+                FractalError::DuplicateName(name) => write!(f, "definition name {:?} is duplicated", name),
+                FractalError::DefinitionInUse(tag) => write!(f, "tag {:08x} is in use", tag),
+                FractalError::NotBase(tag) => write!(f, "tag {:08x} is not a base definition", tag),
+                FractalError::UnregisteredCodec(code) =>
+                    write!(f, "codec of user defined type {:02x} is not registered", code),
+                FractalError::InvalidCustomCode(code) =>
+                    write!(f, "type code {:02x} cannot be used for user defined type (0x80 to 0xfe)", code),
+                FractalError::NotNullable(tag) => write!(f, "tag {:08x} is not nullable", tag),
+                FractalError::NullValue(tag) => write!(f, "value of tag {:08x} is null", tag),
+                FractalError::DanglingReference(reference) => write!(f, "reference {} is dangling", reference),
+                FractalError::AmbiguousReference(reference) =>
+                    write!(f, "reference {} points to more than one data", reference),
+                FractalError::InvalidKey(tag) => write!(f, "data of tag {:08x} cannot be a key of reference", tag),
+                FractalError::BlobMismatch(hash) => write!(f, "blob {:016x} is broken", hash),
+                FractalError::MissingChild => write!(f, "child binary manager cannot found"),
+                FractalError::DataNotFound => write!(f, "data cannot found"),
+                FractalError::Utf8(e) => write!(f, "{}", e),
+                FractalError::Io(e) => write!(f, "{}", e),
+            }
+        }
+    }
+
+    impl std::error::Error for FractalError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                FractalError::Utf8(e) => Some(e),
+                FractalError::Io(e) => Some(e),
+                _ => None,
+            }
+        }
+    }
+
+    impl From<io::Error> for FractalError {
+        fn from(e: io::Error) -> FractalError {
+            FractalError::Io(e)
+        }
+    }
+
+    impl From<Utf8Error> for FractalError {
+        fn from(e: Utf8Error) -> FractalError {
+            FractalError::Utf8(e)
+        }
+    }
+
+    impl From<FromUtf8Error> for FractalError {
+        fn from(e: FromUtf8Error) -> FractalError {
+            FractalError::Utf8(e.utf8_error())
+        }
+    }
+}
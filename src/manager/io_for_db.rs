@@ -2,23 +2,40 @@
 pub mod reader;
 pub mod writer;
 pub mod path_manager;
+pub mod header;
+pub mod checksum;
 
 pub mod io {
 
     const STX: u8 = 0x02;
     const ETX: u8 = 0x03;
 
+    /// Magic number at the head of file.
+    pub const MAGIC: [u8; 4] = *b"OFDB";
+    /// Format version which is written now.  
+    /// Version 2 : records are length-prefixed (STX, length, body, ETX, check sum).  
+    /// Version 3 : file header has kind, creation metadata and definition fingerprint.  
+    /// Version 4 : check sum algorithm is recorded in file header.  
+    /// Version 5 : definition children have min and max cardinality.  
+    /// Version 6 : definition has value constraints.  
+    /// Version 7 : definition has default value.  
+    /// Version 8 : definition has base definition.  
+    /// Version 9 : definition has max depth.  
+    /// Version 10 : numeric constraint bounds are typed (integer or floating point).
+    pub const FORMAT_VERSION: u8 = 10;
+    /// Format version of the file which has no header.  
+    /// Version 1 : records are found by scanning STX/ETX.
+    pub const LEGACY_VERSION: u8 = 1;
+
     use crate::manager::io_for_db::writer::writer::Writer;
     use crate::manager::io_for_db::reader::reader::Reader;
+    use crate::manager::io_for_db::header::header::FileHeader;
+    use crate::manager::io_for_db::checksum::checksum::ChecksumAlgorithm;
+    use crate::manager::error::error::{FractalError, Result};
 
-    use std::convert::TryFrom;
-    use std::error;
-    use std::path::PathBuf;
+    use std::path::Path;
     use std::str;
 
-    // Change the alias to `Box<dyn error::Error>`.
-    type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
-
     /// Read/Write mode
     #[derive(Debug)]
     pub enum Mode {
@@ -32,50 +49,119 @@ pub mod io {
         buf: Vec<u8>,
         /// index which reading now.
         index: usize,
+        /// format version of binary data.
+        version: u8,
+        /// index of STX in the parent binary data.
+        record_start: usize,
+        /// offset of binary data in file.
+        offset: usize,
+        /// check sum algorithm of record.
+        checksum: ChecksumAlgorithm,
         // child binary manager. its size have to 0 or 1.
         pub child: Vec<BinaryManager>
     }
 
+    impl Default for BinaryManager {
+        fn default() -> BinaryManager {
+            BinaryManager::new()
+        }
+    }
+
     impl BinaryManager {
         /// Create new.
         pub fn new() -> BinaryManager {
             let buf: Vec<u8> = Vec::new();
             let child: Vec<BinaryManager> = Vec::new();
             BinaryManager{
-                buf: buf,
+                buf,
                 index: 0,
-                child: child,
+                version: FORMAT_VERSION,
+                record_start: 0,
+                offset: 0,
+                checksum: ChecksumAlgorithm::default(),
+                child,
             }
         }
 
-        /// create child.
+        /// get format version of binary data.
+        pub fn get_version(&self) -> u8 {
+            self.version
+        }
+
+        /// get check sum algorithm of record.
+        pub fn get_checksum(&self) -> ChecksumAlgorithm {
+            self.checksum
+        }
+
+        /// set check sum algorithm of record.  
+        /// It has to be set before records are added.
+        /// * `checksum` - check sum algorithm.
+        pub fn set_checksum(&mut self, checksum: ChecksumAlgorithm) {
+            self.checksum = checksum;
+        }
+
+        /// create child.  
+        /// In read mode, the child has the body of the record which starts at the current index.
+        /// The check sum of the record is verified before the body is read (except legacy version).
         /// * `mode` - Read/Write.
-        pub fn create_child(&mut self, mode: Mode) {
+        pub fn create_child(&mut self, mode: Mode) -> Result<()> {
             let child_vec: Vec<BinaryManager> = Vec::new();
-            let buf: Vec<u8> = match mode {
-                Mode::Read => self.buf[self.index..].to_vec(),
-                Mode::Write => Vec::new(),
+            let record_start = self.index;
+            let (buf, index, offset) = match mode {
+                Mode::Read => {
+                    if self.read_u8()? != STX {
+                        return Err(FractalError::InvalidRecord { index: self.offset + record_start });
+                    }
+                    if self.version == LEGACY_VERSION {
+                        // The end of record is unknown until the child is read.
+                        (self.buf[record_start..].to_vec(), 1, self.offset + record_start)
+                    }
+                    else {
+                        let len = self.read_u32()? as usize;
+                        let body_start = self.index;
+                        let body = self.read_u8_vec(len)?;
+                        self.read_record_end(record_start, record_tag(&body))?;
+                        (body, 0, self.offset + body_start)
+                    }
+                },
+                Mode::Write => (Vec::new(), 0, 0),
             };
             let child = BinaryManager{
-                buf: buf,
-                index: 0,
+                buf,
+                index,
+                version: self.version,
+                record_start,
+                offset,
+                checksum: self.checksum,
                 child: child_vec,
             };
             self.child = vec![child];
+            Ok(())
         }
 
         /// get child binary manager.
         pub fn get_child(&mut self) -> Result<&mut BinaryManager> {
-            match self.child.iter_mut().find(|_| true) {
+            match self.child.first_mut() {
                 Some(child) => Ok(child),
-                None => { panic!("Child binary manager cannot found") },
+                None => Err(FractalError::MissingChild),
             }
         }
 
-        /// Exit the child manager and add the child data.
+        /// Exit the child manager and add the child data as a record.  
+        /// (STX, body length, body, ETX, check sum)
         pub fn end_child_and_add_data(&mut self) -> Result<()> {
-            let end = self.get_child()?.add_end_data();
-            self.buf.append(&mut end?);
+            let body = std::mem::take(&mut self.get_child()?.buf);
+            let len = match u32::try_from(body.len()) {
+                Ok(len) => len,
+                Err(_) => return Err(FractalError::RecordTooLarge(body.len())),
+            };
+            let record_start = self.buf.len();
+            self.add_u8(STX);
+            self.add_u32(len)?;
+            self.buf.extend_from_slice(&body);
+            self.add_u8(ETX);
+            let mut check_sum = self.checksum.to_bytes(&self.buf[record_start..]);
+            self.buf.append(&mut check_sum);
             // Clear child.
             self.child = Vec::new();
             Ok(())
@@ -85,7 +171,7 @@ pub mod io {
         /// * `data` - append data.
         pub fn add_u32(&mut self, data: u32) -> Result<()> {
             let mut vec:Vec<u8> = Vec::new();
-            u32_to_slice(data, &mut vec, 24)?;
+            u32_to_slice(data, &mut vec, 24);
             self.buf.append(&mut vec);
             Ok(())
         }
@@ -109,7 +195,7 @@ pub mod io {
 
         /// add string data.
         /// * `data` - append data.
-        pub fn add_str(&mut self, data: &String) -> Result<()> {
+        pub fn add_str(&mut self, data: &str) -> Result<()> {
             self.add_usize(data.len())?;
             for b in data.as_bytes(){
                 self.buf.push(*b);
@@ -117,62 +203,92 @@ pub mod io {
             Ok(())
         }
 
+        /// add u64 data.
+        /// * `data` - append data.
+        pub fn add_u64(&mut self, data: u64) {
+            self.buf.extend_from_slice(&data.to_be_bytes());
+        }
+
         /// add usize data.
         /// * `data` - append data.
         pub fn add_usize(&mut self, data: usize) -> Result<()> {
             let data = data as u128;
             let mut vec:Vec<u8> = Vec::new();
-            u128_to_slice(data, &mut vec, 120)?;
+            u128_to_slice(data, &mut vec, 120);
             self.buf.append(&mut vec);
             Ok(())
         }
 
-        /// add start data(STX)
-        pub fn add_start_data(&mut self) {
-            self.buf.append(&mut vec![0x00, STX]);
+        /// get binary data.
+        pub fn get_buf(&self) -> &[u8] {
+            &self.buf
         }
 
-        /// add end data (EXT, check sum)
-        pub fn add_end_data(&mut self) -> Result<Vec<u8>> {
-            self.buf.push(ETX);
-            let check_sum = self.check_sum();
-            self.buf.push(check_sum?);
-            Ok(self.buf.iter().as_slice().to_vec())
+        /// get binary data with file header.
+        /// * `header` - file header.
+        pub fn to_bytes(&self, header: &FileHeader) -> Result<Vec<u8>> {
+            let mut b = BinaryManager::new();
+            b.buf.extend_from_slice(&MAGIC);
+            b.add_u8(FORMAT_VERSION);
+            // Records are written by the check sum algorithm of this manager.
+            let mut header = header.clone();
+            header.checksum = self.checksum;
+            header.write_fields(&mut b)?;
+            b.buf.extend_from_slice(&self.buf);
+            Ok(b.buf)
         }
 
-        /// calculate check sum.
-        /// * `data` - calculated data.
-        pub fn check_sum(&mut self) -> Result<u8> {
-            let mut sum: u16 = 0;
-            for d in self.buf.iter_mut() {
-                let mut _d: u16 = TryFrom::try_from(*d)?;
-                // AND mask for avoid overflowing digits
-                sum = (sum + _d) & 0xff;
+        /// Load binary data and read file header.  
+        /// The binary data which has no header is read as legacy version.
+        /// * `bytes` - binary data.
+        pub fn load(&mut self, bytes: Vec<u8>) -> Result<FileHeader> {
+            self.buf = bytes;
+            self.child = Vec::new();
+            self.offset = 0;
+            self.checksum = ChecksumAlgorithm::Sum8;
+            if !self.buf.starts_with(&MAGIC) {
+                self.index = 0;
+                self.version = LEGACY_VERSION;
+                return Ok(FileHeader::unknown(LEGACY_VERSION));
             }
-            let check: u8 = TryFrom::try_from(sum)?;
-            Ok(check)
+            self.index = MAGIC.len();
+            self.version = self.read_u8()?;
+            // The file which has header is written at version 2 or later.
+            if self.version <= LEGACY_VERSION || self.version > FORMAT_VERSION {
+                return Err(FractalError::UnsupportedVersion(self.version));
+            }
+            // Version 2 has only magic number and format version.
+            if self.version == 2 {
+                return Ok(FileHeader::unknown(self.version));
+            }
+            let header = FileHeader::read_fields(self.version, self)?;
+            self.checksum = header.checksum;
+            Ok(header)
         }
 
         /// Write buffer data
-        /// * `data` - file path
-        pub fn write(&self, path: &PathBuf) -> Result<()> {
+        /// * `path` - file path
+        /// * `header` - file header.
+        pub fn write(&self, path: &Path, header: &FileHeader) -> Result<()> {
             let mut writer = Writer::create(path)?;
-            writer.write(&self.buf);
+            writer.write(&self.to_bytes(header)?)?;
             Ok(())
         }
 
         /// Read file data to end.
         /// * `path` - file path.
-        pub fn read_to_end(&mut self, path: &PathBuf) -> Result<()> {
+        pub fn read_to_end(&mut self, path: &Path) -> Result<FileHeader> {
             let mut reader = Reader::open(path)?;
             reader.read_to_end()?;
-            self.buf = reader.result;
-            Ok(())
+            self.load(reader.result)
         }
 
-        /// Proceed to the next STX.  
-        /// Return false if STX is NOT exist or ETX found
-        pub fn next_stx_index(&mut self) -> bool {
+        /// Return true if the next record exists.  
+        /// Legacy version proceeds to the next STX and return false if STX is NOT exist or ETX found.
+        pub fn next_record(&mut self) -> bool {
+            if self.version != LEGACY_VERSION {
+                return self.index < self.buf.len();
+            }
             loop {
                 // could not found STX.
                 if self.index >= self.buf.len() {
@@ -193,129 +309,152 @@ pub mod io {
             true
         }
 
-        /// read check sum.  
-        /// return index if check sum is Ok and 0 if check sum error.
-        pub fn read_check_sum(&mut self) -> Result<usize> {
-            let check_sum = self.buf[self.index] as u8;
-            // get buf until check sum data.
-            self.buf = self.buf[..self.index].to_vec();
-            if check_sum == self.check_sum()? {
-                Ok(self.index)
+        /// read child data.  
+        /// Check that the body of child record is read to the end, and exit the child manager.
+        /// Legacy version reads ETX and check sum of child record here.
+        pub fn read_child(&mut self) -> Result<()> {
+            let child = match self.child.pop() {
+                Some(child) => child,
+                None => return Err(FractalError::MissingChild),
+            };
+            if child.version != LEGACY_VERSION {
+                if child.index != child.buf.len() {
+                    return Err(FractalError::InvalidRecord { index: child.record_start });
+                }
+                return Ok(());
+            }
+            self.index = child.record_start + child.index;
+            self.read_record_end(child.record_start, record_tag(&child.buf[1..]))
+        }
+
+        /// read ETX and check sum of record, and verify the check sum.
+        /// * `record_start` - index of STX.
+        /// * `tag` - tag of record which is reported on mismatch.
+        fn read_record_end(&mut self, record_start: usize, tag: Option<u32>) -> Result<()> {
+            let etx_index = self.index;
+            if self.read_u8()? != ETX {
+                return Err(FractalError::InvalidRecord { index: self.offset + etx_index });
+            }
+            let mut expected: u64 = 0;
+            for b in self.read_slice(self.checksum.size())?.iter() {
+                expected = expected << 8 | u64::from(*b);
+            }
+            let actual = self.checksum.calculate(&self.buf[record_start..=etx_index]);
+            if expected == actual {
+                Ok(())
             }
             else {
-                panic!("Check sum error!");
+                Err(FractalError::ChecksumMismatch {
+                    offset: self.offset + record_start,
+                    tag,
+                    expected,
+                    actual,
+                })
             }
         }
 
-        /// read child data.  
-        /// return true if child check sum is Ok and false if child check sum error.
-        pub fn read_child(&mut self) -> Result<()> {
-            self.index += self.get_child()?.read_check_sum()?;
-            Ok(())
+        /// get buffer slice which is read next.
+        /// * `len` - data length.
+        fn read_slice(&mut self, len: usize) -> Result<&[u8]> {
+            let start = self.index;
+            match start.checked_add(len).and_then(|end| self.buf.get(start..end)) {
+                Some(slice) => {
+                    self.index += len;
+                    Ok(slice)
+                },
+                None => Err(FractalError::Truncated { index: start, len }),
+            }
         }
 
         /// read u8 data.
-        pub fn read_u8(&mut self) -> u8 {
-            let u = self.buf[self.index];
-            self.index += 1;
-            u
+        pub fn read_u8(&mut self) -> Result<u8> {
+            Ok(self.read_slice(1)?[0])
         }
 
         /// read u32 data.
         pub fn read_u32(&mut self) -> Result<u32> {
             let mut data: u32 = 0;
-            for (i, b) in self.buf[self.index..self.index + 4].iter().enumerate() {
-                let _b: u32 = TryFrom::try_from(*b)?;
-                data = data | (_b << ((3 - i) * 8) as u32);
+            for (i, b) in self.read_slice(4)?.iter().enumerate() {
+                data |= u32::from(*b) << ((3 - i) * 8);
+            }
+            Ok(data)
+        }
+
+        /// read u64 data.
+        pub fn read_u64(&mut self) -> Result<u64> {
+            let mut data: u64 = 0;
+            for b in self.read_slice(8)?.iter() {
+                data = data << 8 | u64::from(*b);
             }
-            self.index += 4;
             Ok(data)
         }
 
         /// read u128 data.
         pub fn read_u128(&mut self) -> Result<u128> {
             let mut data: u128 = 0;
-            for (i, b) in self.buf[self.index..self.index + 16].iter().enumerate() {
-                let _b: u128 = TryFrom::try_from(*b)?;
-                data = data | (_b << ((15 - i) * 8) as u128);
+            for (i, b) in self.read_slice(16)?.iter().enumerate() {
+                data |= u128::from(*b) << ((15 - i) * 8);
             }
-            self.index += 16;
             Ok(data)
         }
 
+        /// read length data.
+        pub fn read_usize(&mut self) -> Result<usize> {
+            let index = self.index;
+            let len = self.read_u128()?;
+            match usize::try_from(len) {
+                Ok(len) => Ok(len),
+                Err(_) => Err(FractalError::Truncated { index, len: usize::MAX }),
+            }
+        }
+
         /// read u8 vec data.
         /// * `len` - data length.
-        pub fn read_u8_vec(&mut self, len: usize) -> Vec<u8> {
-            let vec = &self.buf[self.index..self.index + len];
-            self.index += len;
-            vec.to_vec()
+        pub fn read_u8_vec(&mut self, len: usize) -> Result<Vec<u8>> {
+            Ok(self.read_slice(len)?.to_vec())
         }
 
         /// read string data.
         pub fn read_str(&mut self) -> Result<String> {
             // read string of length.
-            let len: usize = self.read_u128()? as usize;
+            let len: usize = self.read_usize()?;
             // read string.
-            let str = str::from_utf8(&self.buf[self.index .. self.index + len])?;
-            self.index += len;
+            let str = str::from_utf8(self.read_slice(len)?)?;
             Ok(str.to_string())
         }
+    }
 
-        /// Read String data.  It use when read data value which type is String.
-        pub fn read_str_value(&mut self) -> Result<Vec<u32>> {
-            // read string of length.
-            let len: usize = self.read_u128()? as usize;
-            let mut vec: Vec<u32> = Vec::new();
-            vec = u128_to_u32slice(len as u128, &mut vec, 96)?;
-            // read string.
-            for _ in 0..len {
-                self.read_u32()?;
-            }
-            self.index += len;
-            Ok(vec)
-        }
+    /// get tag of record. Tag is the first data of record body.
+    /// * `body` - record body.
+    fn record_tag(body: &[u8]) -> Option<u32> {
+        let bytes = body.get(..4)?;
+        Some(u32::from_be_bytes(bytes.try_into().ok()?))
     }
 
     /// Convert u32 to u8 slice
     /// * `data` - original data.
     /// * `buf` - result.
     /// * `tags` - digit. it must set 24 when use.
-    fn u32_to_slice(data: u32, mut buf: &mut Vec<u8>, digit: u32) -> Result<Vec<u8>> {
-        let a:u32 = data >> digit & 0xff;
-        let a: u8 = TryFrom::try_from(a)?;
+    fn u32_to_slice(data: u32, buf: &mut Vec<u8>, digit: u32) -> Vec<u8> {
+        let a: u8 = (data >> digit & 0xff) as u8;
         buf.push(a);
         if digit >= 8{
-            u32_to_slice(data, &mut buf, digit - 8)?;
+            u32_to_slice(data, buf, digit - 8);
         }
-        Ok(buf.to_vec())
+        buf.to_vec()
     }
 
     /// Convert u128 to u8 slice
     /// * `data` - original data.
     /// * `buf` - result.
     /// * `tags` - digit. it must set 120 when use.
-    pub fn u128_to_slice(data: u128, mut buf: &mut Vec<u8>, digit: u128) -> Result<Vec<u8>> {
-        let a:u128 = data >> digit & 0xff;
-        let a: u8 = TryFrom::try_from(a)?;
+    pub fn u128_to_slice(data: u128, buf: &mut Vec<u8>, digit: u128) -> Vec<u8> {
+        let a: u8 = (data >> digit & 0xff) as u8;
         buf.push(a);
         if digit >= 8{
-            u128_to_slice(data, &mut buf, digit - 8)?;
+            u128_to_slice(data, buf, digit - 8);
         }
-        Ok(buf.to_vec())
-    }
-
-    /// Convert u128 to u32 slice
-    /// * `data` - original data.
-    /// * `buf` - result.
-    /// * `tags` - digit. it must set 96 when use.
-    fn u128_to_u32slice(data: u128, mut buf: &mut Vec<u32>, digit: u128) -> Result<Vec<u32>> {
-        let a:u128 = data >> digit & 0xffffffff;
-        let a: u32 = TryFrom::try_from(a)?;
-        buf.push(a);
-        if digit >= 32{
-            u128_to_u32slice(data, &mut buf, digit - 32)?;
-        }
-        Ok(buf.to_vec())
+        buf.to_vec()
     }
 
     #[cfg(test)]
@@ -327,8 +466,7 @@ pub mod io {
     #[cfg(test)]
     speculate! {
 
-        use std::{thread, time};
-        use rstest::rstest;
+        use crate::manager::io_for_db::header::header::FileKind;
 
         describe "module" {
             it "u32 to slice" {
@@ -338,11 +476,104 @@ pub mod io {
                 assert_eq!(buf, vec![0x20, 0x10, 0x00, 0x10]);
             }
 
-            it "u128 to u32slice" {
-                let tag: u128 = 0x1010_0010_2010_0010_3010_0010_4010_0010;
-                let mut buf: Vec<u32> = Vec::new();
-                u128_to_u32slice(tag, &mut buf, 96);
-                assert_eq!(buf, vec![0x1010_0010, 0x2010_0010, 0x3010_0010, 0x4010_0010]);
+            it "escape safe record" {
+                let mut b = BinaryManager::new();
+                for tag in [0x0203_0203_u32, 0x0302_0302] {
+                    b.create_child(Mode::Write).unwrap();
+                    b.get_child().unwrap().add_u32(tag).unwrap();
+                    b.get_child().unwrap().add_str("\u{2}\u{3}").unwrap();
+                    b.end_child_and_add_data().unwrap();
+                }
+
+                let mut r = BinaryManager::new();
+                r.load(b.to_bytes(&FileHeader::new(FileKind::Data, None)).unwrap()).unwrap();
+                assert_eq!(r.get_version(), FORMAT_VERSION);
+                let mut tags = Vec::new();
+                while r.next_record() {
+                    r.create_child(Mode::Read).unwrap();
+                    tags.push(r.get_child().unwrap().read_u32().unwrap());
+                    assert_eq!(r.get_child().unwrap().read_str().unwrap(), "\u{2}\u{3}");
+                    r.read_child().unwrap();
+                }
+                assert_eq!(tags, vec![0x0203_0203, 0x0302_0302]);
+            }
+
+            it "legacy record" {
+                let mut r = BinaryManager::new();
+                r.load(vec![0x00, STX, 0x12, 0x34, 0x56, 0x78, ETX, 0x19]).unwrap();
+                assert_eq!(r.get_version(), LEGACY_VERSION);
+                assert!(r.next_record());
+                r.create_child(Mode::Read).unwrap();
+                assert_eq!(r.get_child().unwrap().read_u32().unwrap(), 0x1234_5678);
+                r.read_child().unwrap();
+                assert!(!r.next_record());
+            }
+
+            it "version 2 header" {
+                let mut r = BinaryManager::new();
+                let header = r.load(vec![b'O', b'F', b'D', b'B', 2]).unwrap();
+                assert_eq!(header, FileHeader::unknown(2));
+                assert!(!r.next_record());
+            }
+
+            it "unsupported version" {
+                for version in [0, LEGACY_VERSION, FORMAT_VERSION + 1] {
+                    let mut bytes = MAGIC.to_vec();
+                    bytes.push(version);
+                    match BinaryManager::new().load(bytes) {
+                        Err(FractalError::UnsupportedVersion(v)) => assert_eq!(v, version),
+                        _ => panic!("unsupported version is not detected"),
+                    }
+                }
+            }
+
+            it "corrupted record" {
+                for algorithm in [ChecksumAlgorithm::Crc32c, ChecksumAlgorithm::XxHash64] {
+                    let mut b = BinaryManager::new();
+                    b.set_checksum(algorithm);
+                    for tag in [0x1111_1111_u32, 0x2222_2222] {
+                        b.create_child(Mode::Write).unwrap();
+                        b.get_child().unwrap().add_u32(tag).unwrap();
+                        b.get_child().unwrap().add_u8(0x01);
+                        b.get_child().unwrap().add_u8(0x02);
+                        b.end_child_and_add_data().unwrap();
+                    }
+                    let mut bytes = b.to_bytes(&FileHeader::new(FileKind::Data, None)).unwrap();
+                    // Swap the value bytes of the second record.
+                    let record_len = 1 + 4 + 6 + 1 + algorithm.size();
+                    let record_start = bytes.len() - record_len;
+                    bytes.swap(record_start + 9, record_start + 10);
+
+                    let mut r = BinaryManager::new();
+                    assert_eq!(r.load(bytes).unwrap().checksum, algorithm);
+                    // The record is verified before its body is read.
+                    let mut result = Ok(());
+                    while r.next_record() {
+                        result = r.create_child(Mode::Read);
+                        if result.is_err() {
+                            break;
+                        }
+                        r.get_child().unwrap().read_u32().unwrap();
+                        r.get_child().unwrap().read_u8_vec(2).unwrap();
+                        r.read_child().unwrap();
+                    }
+                    match result {
+                        Err(FractalError::ChecksumMismatch { offset, tag, .. }) => {
+                            assert_eq!(offset, record_start);
+                            assert_eq!(tag, Some(0x2222_2222));
+                        },
+                        _ => panic!("corrupted record is not detected"),
+                    }
+                }
+            }
+
+            it "truncated" {
+                let mut b = BinaryManager::new();
+                b.add_u8(0x01);
+                match b.read_u32() {
+                    Err(FractalError::Truncated { index, len }) => assert_eq!((index, len), (0, 4)),
+                    _ => panic!("truncated data is not detected"),
+                }
             }
         }
     }
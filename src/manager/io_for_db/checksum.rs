@@ -0,0 +1,104 @@
+#[allow(clippy::module_inception)]
+pub mod checksum {
+
+    use crate::manager::error::error::{FractalError, Result};
+
+    /// Check sum algorithm of record.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum ChecksumAlgorithm {
+        /// 8 bit additive check sum. It is used by format version 1 to 3.
+        Sum8,
+        /// CRC-32C (Castagnoli).
+        #[default]
+        Crc32c,
+        /// xxHash64.
+        XxHash64,
+    }
+
+    impl ChecksumAlgorithm {
+        /// Convert algorithm code to algorithm.
+        /// * `buf` - algorithm code.
+        pub fn u8_to_algorithm(buf: u8) -> Result<ChecksumAlgorithm> {
+            match buf {
+                0x00 => Ok(ChecksumAlgorithm::Sum8),
+                0x01 => Ok(ChecksumAlgorithm::Crc32c),
+                0x02 => Ok(ChecksumAlgorithm::XxHash64),
+                _ => Err(FractalError::InvalidHeader),
+            }
+        }
+
+        /// get algorithm by binary.
+        pub fn get_algorithm_num(&self) -> u8 {
+            match self {
+                ChecksumAlgorithm::Sum8 => 0x00,
+                ChecksumAlgorithm::Crc32c => 0x01,
+                ChecksumAlgorithm::XxHash64 => 0x02,
+            }
+        }
+
+        /// get byte size of check sum.
+        pub fn size(&self) -> usize {
+            match self {
+                ChecksumAlgorithm::Sum8 => 1,
+                ChecksumAlgorithm::Crc32c => 4,
+                ChecksumAlgorithm::XxHash64 => 8,
+            }
+        }
+
+        /// calculate check sum.
+        /// * `data` - calculated data.
+        pub fn calculate(&self, data: &[u8]) -> u64 {
+            match self {
+                ChecksumAlgorithm::Sum8 => {
+                    let mut sum: u16 = 0;
+                    for d in data.iter() {
+                        // AND mask for avoid overflowing digits
+                        sum = (sum + u16::from(*d)) & 0xff;
+                    }
+                    u64::from(sum)
+                },
+                ChecksumAlgorithm::Crc32c => u64::from(crc32c::crc32c(data)),
+                ChecksumAlgorithm::XxHash64 => xxhash_rust::xxh64::xxh64(data, 0),
+            }
+        }
+
+        /// calculate check sum and convert it to binary (big endian).
+        /// * `data` - calculated data.
+        pub fn to_bytes(&self, data: &[u8]) -> Vec<u8> {
+            let sum = self.calculate(data).to_be_bytes();
+            sum[8 - self.size()..].to_vec()
+        }
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "checksum" {
+            it "known values" {
+                assert_eq!(ChecksumAlgorithm::Sum8.calculate(&[0xff, 0x02]), 0x01);
+                assert_eq!(ChecksumAlgorithm::Crc32c.calculate(b"123456789"), 0xe306_9283);
+                assert_eq!(ChecksumAlgorithm::XxHash64.calculate(b""), 0xef46_db37_51d8_e999);
+            }
+
+            it "swapped bytes" {
+                let sum = ChecksumAlgorithm::Crc32c.calculate(&[0x01, 0x02]);
+                assert_ne!(sum, ChecksumAlgorithm::Crc32c.calculate(&[0x02, 0x01]));
+                let sum = ChecksumAlgorithm::XxHash64.calculate(&[0x01, 0x02]);
+                assert_ne!(sum, ChecksumAlgorithm::XxHash64.calculate(&[0x02, 0x01]));
+            }
+
+            it "algorithm code" {
+                for algorithm in [ChecksumAlgorithm::Sum8, ChecksumAlgorithm::Crc32c, ChecksumAlgorithm::XxHash64] {
+                    let code = algorithm.get_algorithm_num();
+                    assert_eq!(ChecksumAlgorithm::u8_to_algorithm(code).unwrap(), algorithm);
+                    assert_eq!(algorithm.to_bytes(b"test").len(), algorithm.size());
+                }
+            }
+        }
+    }
+}
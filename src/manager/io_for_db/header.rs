@@ -0,0 +1,159 @@
+#[allow(clippy::module_inception)]
+pub mod header {
+
+    use crate::manager::io_for_db::io::BinaryManager;
+    use crate::manager::io_for_db::checksum::checksum::ChecksumAlgorithm;
+    use crate::manager::error::error::{FractalError, Result};
+
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// Kind of file.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum FileKind {
+        /// Definition file (dbdf.mfd).
+        Definition,
+        /// Data file.
+        Data,
+        /// The file is written before the kind is recorded.
+        Unknown,
+    }
+
+    impl FileKind {
+        /// Convert kind code to kind.
+        /// * `buf` - kind code.
+        pub fn u8_to_kind(buf: u8) -> Result<FileKind> {
+            match buf {
+                0x00 => Ok(FileKind::Definition),
+                0x01 => Ok(FileKind::Data),
+                _ => Err(FractalError::InvalidHeader),
+            }
+        }
+
+        /// get kind by binary.
+        pub fn get_kind_num(&self) -> u8 {
+            match self {
+                FileKind::Definition => 0x00,
+                FileKind::Data => 0x01,
+                FileKind::Unknown => 0xff,
+            }
+        }
+    }
+
+    /// File header. It is written after the magic number and the format version.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct FileHeader {
+        /// format version.
+        pub version: u8,
+        /// kind of file.
+        pub kind: FileKind,
+        /// created time (milliseconds from UNIX epoch).
+        pub created_at: Option<u64>,
+        /// crate version which wrote the file.
+        pub writer: String,
+        /// fingerprint of the definitions which the data file is written against.
+        pub def_fingerprint: Option<u64>,
+        /// check sum algorithm of record.
+        pub checksum: ChecksumAlgorithm,
+    }
+
+    impl FileHeader {
+        /// Create new header of the file which is written now.
+        /// * `kind` - kind of file.
+        /// * `def_fingerprint` - fingerprint of definitions (only data file).
+        pub fn new(kind: FileKind, def_fingerprint: Option<u64>) -> FileHeader {
+            let created_at = SystemTime::now().duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0);
+            FileHeader {
+                version: crate::manager::io_for_db::io::FORMAT_VERSION,
+                kind,
+                created_at: Some(created_at),
+                writer: env!("CARGO_PKG_VERSION").to_string(),
+                def_fingerprint,
+                checksum: ChecksumAlgorithm::default(),
+            }
+        }
+
+        /// Create header of the file which does not record header fields.
+        /// * `version` - format version.
+        pub fn unknown(version: u8) -> FileHeader {
+            FileHeader {
+                version,
+                kind: FileKind::Unknown,
+                created_at: None,
+                writer: String::new(),
+                def_fingerprint: None,
+                checksum: ChecksumAlgorithm::Sum8,
+            }
+        }
+
+        /// write header fields.
+        /// * `b` - binary data manager.
+        pub fn write_fields(&self, b: &mut BinaryManager) -> Result<()> {
+            b.add_u8(self.kind.get_kind_num());
+            b.add_u64(self.created_at.unwrap_or(0));
+            b.add_str(&self.writer)?;
+            match self.def_fingerprint {
+                Some(fingerprint) => {
+                    b.add_bool(true);
+                    b.add_u64(fingerprint);
+                },
+                None => b.add_bool(false),
+            }
+            b.add_u8(self.checksum.get_algorithm_num());
+            Ok(())
+        }
+
+        /// read header fields.
+        /// * `version` - format version.
+        /// * `b` - binary data manager.
+        pub fn read_fields(version: u8, b: &mut BinaryManager) -> Result<FileHeader> {
+            let mut header = FileHeader::unknown(version);
+            header.kind = FileKind::u8_to_kind(b.read_u8()?)?;
+            header.created_at = Some(b.read_u64()?);
+            header.writer = b.read_str()?;
+            if b.read_u8()? != 0 {
+                header.def_fingerprint = Some(b.read_u64()?);
+            }
+            // Version 3 records check sum by Sum8.
+            if version >= 4 {
+                header.checksum = ChecksumAlgorithm::u8_to_algorithm(b.read_u8()?)?;
+            }
+            Ok(header)
+        }
+    }
+
+    /// Calculate 64 bit fingerprint (FNV-1a).
+    /// * `data` - calculated data.
+    pub fn fingerprint(data: &[u8]) -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for d in data {
+            hash ^= u64::from(*d);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        hash
+    }
+
+    #[cfg(test)]
+    extern crate speculate;
+
+    #[cfg(test)]
+    use speculate::speculate;
+
+    #[cfg(test)]
+    speculate! {
+        describe "header" {
+            it "fingerprint" {
+                assert_eq!(fingerprint(b""), 0xcbf2_9ce4_8422_2325);
+                assert_eq!(fingerprint(b"a"), 0xaf63_dc4c_8601_ec8c);
+            }
+
+            it "header r/w" {
+                let header = FileHeader::new(FileKind::Data, Some(0x1234_5678_9abc_def0));
+                let bytes = BinaryManager::new().to_bytes(&header).unwrap();
+                let mut r = BinaryManager::new();
+                assert_eq!(r.load(bytes).unwrap(), header);
+            }
+        }
+    }
+}
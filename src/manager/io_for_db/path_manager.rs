@@ -1,12 +1,12 @@
 
+#[allow(clippy::module_inception)]
 pub mod path_manager {
+    use crate::manager::data::blob::blob::Blob;
+    use crate::manager::error::error::Result;
+
     use std::fs;
-    use std::error;
     use std::path::PathBuf;
 
-    // Change the alias to `Box<dyn error::Error>`.
-    type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
-
     #[derive(Clone, Debug)]
     pub struct PathManager {
         pub dir_path: PathBuf,
@@ -22,16 +22,16 @@ pub mod path_manager {
         /// * `data_name` - Data name.
         pub fn new(dir_path: PathBuf, table_name: String, data_name: String) -> PathManager {
             PathManager {
-                dir_path: dir_path,
-                table_name: table_name,
-                data_name: data_name,
+                dir_path,
+                table_name,
+                data_name,
             }
         }
 
         /// Definition file path.
         pub fn get_def_path(&self) -> Result<PathBuf> {
             let path = &mut self.dir_path.clone();
-            path.push(self.table_name.to_string());
+            path.push(&self.table_name);
             path.push("org");
             if !path.exists() {
                 fs::create_dir_all(&path)?;
@@ -43,7 +43,7 @@ pub mod path_manager {
         /// Data file path.
         pub fn get_data_path(&self) -> Result<PathBuf> {
             let path = &mut self.dir_path.clone();
-            path.push(self.table_name.to_string());
+            path.push(&self.table_name);
             path.push("org/data");
             if !path.exists() {
                 fs::create_dir_all(&path)?;
@@ -51,5 +51,25 @@ pub mod path_manager {
             path.push(format!("{}.mfd", self.data_name));
             Ok(path.to_path_buf())
         }
+
+        /// Blob directory path. It is next to the data file.
+        pub fn get_blob_dir(&self) -> Result<PathBuf> {
+            let path = &mut self.dir_path.clone();
+            path.push(&self.table_name);
+            path.push("org/data");
+            path.push(format!("{}.blob", self.data_name));
+            if !path.exists() {
+                fs::create_dir_all(&path)?;
+            }
+            Ok(path.to_path_buf())
+        }
+
+        /// Blob file path. Blob files are stored in the blob directory.
+        /// * `hash` - content hash of blob.
+        pub fn get_blob_path(&self, hash: u64) -> Result<PathBuf> {
+            let mut path = self.get_blob_dir()?;
+            path.push(Blob::file_name(hash));
+            Ok(path)
+        }
     }
 }
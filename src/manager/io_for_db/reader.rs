@@ -1,4 +1,5 @@
 
+#[allow(clippy::module_inception)]
 pub mod reader {
 
     use std::fs::File;
@@ -14,24 +15,17 @@ pub mod reader {
     impl Reader {
         /// Open file.
         /// * `path` - File path.
-        pub fn open(path: &std::path::PathBuf) -> Result<Reader, std::io::Error> {
+        pub fn open(path: &std::path::Path) -> Result<Reader, std::io::Error> {
             let file = File::open(path)?;
             let buf = Vec::new();
             Ok(
                 Reader{
-                    file: file,
+                    file,
                     result: buf,
                 }
             )
         }
 
-        /// Read file.
-        #[warn(dead_code)]
-        pub fn read(&mut self) -> Result<usize, std::io::Error> {
-            self.result = Vec::new();
-            self.file.read(&mut self.result)
-        }
-
         /// Read file to end.
         pub fn read_to_end(&mut self) -> Result<usize, std::io::Error> {
             self.result = Vec::new();
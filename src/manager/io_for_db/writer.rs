@@ -1,4 +1,5 @@
 
+#[allow(clippy::module_inception)]
 pub mod writer {
 
     use std::fs::{File, OpenOptions};
@@ -6,28 +7,12 @@ pub mod writer {
 
     /// Write file.
     pub struct Writer {
-        path: std::path::PathBuf,
         file: File,
     }
 
     impl Writer {
-        /// Open file in write-read mode.
-        /// This function panic when file does not exist.
-        pub fn open (path : &std::path::PathBuf) -> Result<Writer, std::io::Error> {
-            let f = OpenOptions::new()
-                .read(true)
-                .write(true)
-                .create(true)
-                .append(true)
-                .open(path)?;
-            Ok(Writer {
-                path: path.to_path_buf(),
-                file: f,
-            })
-        }
-
         /// Opens a file in write-only mode. 
-        pub fn create (path : &std::path::PathBuf) -> Result<Writer, std::io::Error> {
+        pub fn create (path : &std::path::Path) -> Result<Writer, std::io::Error> {
             let f = OpenOptions::new()
                 .write(true)
                 .create(true)
@@ -35,7 +20,6 @@ pub mod writer {
                 .open(path)?;
             Ok(
                 Writer {
-                    path: path.to_path_buf(),
                     file: f,
                 }
             )
@@ -43,8 +27,8 @@ pub mod writer {
 
         /// write on file
         /// * `buf` - Written data on file.
-        pub fn write(&mut self, buf: &[u8]) {
-            self.file.write(buf).unwrap();
+        pub fn write(&mut self, buf: &[u8]) -> Result<(), std::io::Error> {
+            self.file.write_all(buf)
         }
     }
 }